@@ -0,0 +1,100 @@
+//! typed wrappers for non-cartesian coordinates, these deliberately do not implement any
+//! arithmetic so they can not be mixed up with cartesian vectors
+
+use num::traits::Float;
+
+/// 2 dimensional polar coordinates, `theta` is measured counter clockwise from the x axis
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Polar<T> {
+    pub r: T,
+    pub theta: T
+}
+
+/// 3 dimensional spherical coordinates, `theta` is the azimuth measured counter clockwise
+/// from the x axis in the xy plane and `phi` is the inclination measured from the z axis
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Spherical<T> {
+    pub r: T,
+    pub theta: T,
+    pub phi: T
+}
+
+/// 3 dimensional cylindrical coordinates, `r` and `theta` are the polar coordinates of the
+/// projection onto the xy plane
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Cylindrical<T> {
+    pub r: T,
+    pub theta: T,
+    pub z: T
+}
+
+impl<T: Float> Polar<T> {
+    pub fn new(r: T, theta: T) -> Self {
+        Polar { r, theta }
+    }
+
+    /// converts from cartesian `(x, y)`
+    pub fn from_cartesian(x: T, y: T) -> Self {
+        Polar { r: x.hypot(y), theta: y.atan2(x) }
+    }
+
+    /// converts to cartesian `(x, y)`
+    pub fn to_cartesian(&self) -> (T, T) {
+        let (sin, cos) = self.theta.sin_cos();
+
+        (self.r * cos, self.r * sin)
+    }
+}
+
+impl<T: Float> Spherical<T> {
+    pub fn new(r: T, theta: T, phi: T) -> Self {
+        Spherical { r, theta, phi }
+    }
+
+    /// converts from cartesian `(x, y, z)`
+    pub fn from_cartesian(x: T, y: T, z: T) -> Self {
+        let rho = x.hypot(y);
+
+        Spherical { r: rho.hypot(z), theta: y.atan2(x), phi: rho.atan2(z) }
+    }
+
+    /// converts to cartesian `(x, y, z)`
+    pub fn to_cartesian(&self) -> (T, T, T) {
+        let (sin_theta, cos_theta) = self.theta.sin_cos();
+        let (sin_phi, cos_phi) = self.phi.sin_cos();
+
+        (self.r * sin_phi * cos_theta, self.r * sin_phi * sin_theta, self.r * cos_phi)
+    }
+}
+
+impl<T: Float> Cylindrical<T> {
+    pub fn new(r: T, theta: T, z: T) -> Self {
+        Cylindrical { r, theta, z }
+    }
+
+    /// converts from cartesian `(x, y, z)`
+    pub fn from_cartesian(x: T, y: T, z: T) -> Self {
+        Cylindrical { r: x.hypot(y), theta: y.atan2(x), z }
+    }
+
+    /// converts to cartesian `(x, y, z)`
+    pub fn to_cartesian(&self) -> (T, T, T) {
+        let (sin, cos) = self.theta.sin_cos();
+
+        (self.r * cos, self.r * sin, self.z)
+    }
+}
+
+impl<T: Float> From<Spherical<T>> for Cylindrical<T> {
+    fn from(value: Spherical<T>) -> Self {
+        let (sin_phi, cos_phi) = value.phi.sin_cos();
+
+        Cylindrical { r: value.r * sin_phi, theta: value.theta, z: value.r * cos_phi }
+    }
+}
+
+impl<T: Float> From<Cylindrical<T>> for Spherical<T> {
+    fn from(value: Cylindrical<T>) -> Self {
+        Spherical { r: value.r.hypot(value.z), theta: value.theta, phi: value.r.atan2(value.z) }
+    }
+}
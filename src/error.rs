@@ -0,0 +1,101 @@
+//! the crate level error type, every fallible operation's error converts into it with `?`
+
+#[cfg(feature = "no_std")]
+use core::fmt;
+#[cfg(not(feature = "no_std"))]
+use std::fmt;
+
+#[cfg(any(feature = "sized", feature = "no_std"))]
+use vector_sized::{TryFromVectorError, NotPositiveDefiniteError, SingularMatrixError};
+#[cfg(not(feature = "no_std"))]
+use matrix::{MatrixError, ShapeMismatch};
+
+/// error returned when an element-wise operation is given vectors of different dimensions
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DimensionMismatch {
+    pub left: usize,
+    pub right: usize
+}
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "dimension mismatch, {} != {}", self.left, self.right)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl ::std::error::Error for DimensionMismatch {}
+
+/// any error produced by this crate
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Error {
+    DimensionMismatch(DimensionMismatch),
+    #[cfg(any(feature = "sized", feature = "no_std"))]
+    TryFromVector(TryFromVectorError),
+    #[cfg(any(feature = "sized", feature = "no_std"))]
+    NotPositiveDefinite(NotPositiveDefiniteError),
+    #[cfg(any(feature = "sized", feature = "no_std"))]
+    SingularMatrix(SingularMatrixError),
+    #[cfg(not(feature = "no_std"))]
+    Matrix(MatrixError)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::DimensionMismatch(ref err) => write!(f, "{}", err),
+            #[cfg(any(feature = "sized", feature = "no_std"))]
+            Error::TryFromVector(ref err) => write!(f, "{}", err),
+            #[cfg(any(feature = "sized", feature = "no_std"))]
+            Error::NotPositiveDefinite(ref err) => write!(f, "{}", err),
+            #[cfg(any(feature = "sized", feature = "no_std"))]
+            Error::SingularMatrix(ref err) => write!(f, "{}", err),
+            #[cfg(not(feature = "no_std"))]
+            Error::Matrix(ref err) => write!(f, "{}", err)
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl ::std::error::Error for Error {}
+
+impl From<DimensionMismatch> for Error {
+    fn from(value: DimensionMismatch) -> Self {
+        Error::DimensionMismatch(value)
+    }
+}
+
+#[cfg(any(feature = "sized", feature = "no_std"))]
+impl From<TryFromVectorError> for Error {
+    fn from(value: TryFromVectorError) -> Self {
+        Error::TryFromVector(value)
+    }
+}
+
+#[cfg(any(feature = "sized", feature = "no_std"))]
+impl From<NotPositiveDefiniteError> for Error {
+    fn from(value: NotPositiveDefiniteError) -> Self {
+        Error::NotPositiveDefinite(value)
+    }
+}
+
+#[cfg(any(feature = "sized", feature = "no_std"))]
+impl From<SingularMatrixError> for Error {
+    fn from(value: SingularMatrixError) -> Self {
+        Error::SingularMatrix(value)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl From<MatrixError> for Error {
+    fn from(value: MatrixError) -> Self {
+        Error::Matrix(value)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl From<ShapeMismatch> for Error {
+    fn from(value: ShapeMismatch) -> Self {
+        Error::Matrix(value.into())
+    }
+}
@@ -0,0 +1,548 @@
+//! the Matrix Market exchange format, a `%%MatrixMarket matrix <format> <field> <symmetry>` header
+//! followed by `%` comments, a size line and one entry per line with 1-based indices
+
+use InVector;
+use matrix::Matrix;
+use sparse::{CooMatrix, CsrMatrix, CscMatrix};
+use vector::Vector;
+
+use std::prelude::v1::*;
+use std::io::{self, BufRead, Write};
+use std::fmt;
+use std::mem;
+
+use num::traits::*;
+use num::complex::Complex;
+
+/// how the entries of a file are laid out
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    /// only the stored entries, as `row col value` lines
+    Coordinate,
+    /// every entry in column-major order, one value per line
+    Array
+}
+
+/// the kind of value stored in each entry
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Field {
+    Real,
+    Integer,
+    /// a real and an imaginary part
+    Complex,
+    /// no value is stored, every listed entry is one, only valid for the coordinate format
+    Pattern
+}
+
+/// which entries of the matrix are stored
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Symmetry {
+    General,
+    /// only the lower triangle is stored, and `a[(j, i)] == a[(i, j)]`
+    Symmetric
+}
+
+/// the first line of a Matrix Market file
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Header {
+    pub format: Format,
+    pub field: Field,
+    pub symmetry: Symmetry
+}
+
+/// what went wrong while parsing a file
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParseErrorKind {
+    /// the first line is not a Matrix Market header
+    Header,
+    /// the header names an object, format, field or symmetry that is not supported
+    Unsupported,
+    /// the file's field can not be read into the element type, like complex values into `f64`
+    FieldMismatch,
+    /// the size line is missing or malformed
+    Size,
+    /// an entry is malformed
+    Entry,
+    /// an entry lies outside of the matrix
+    OutOfBounds,
+    /// a symmetric matrix is not square or has an entry above its diagonal
+    NotSymmetric,
+    /// the file ended before all of the entries announced by the size line
+    MissingEntries,
+    /// the file has more entries than announced by the size line
+    ExtraEntries,
+    /// a vector was read from a matrix with more than one column
+    NotColumn,
+    /// the size line announces a result too large to allocate
+    TooLarge
+}
+
+/// error returned when a file is not valid Matrix Market, `line` is 1-based
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub kind: ParseErrorKind
+}
+
+/// errors produced while reading a Matrix Market file
+#[derive(Debug)]
+pub enum MatrixMarketError {
+    Io(io::Error),
+    Parse(ParseError)
+}
+
+/// an element type that can be read from and written to Matrix Market files
+pub trait MatrixMarketValue: Sized {
+    /// the field written to the header for this type
+    const FIELD: Field;
+
+    /// checks if values stored with `field` can be read into this type
+    fn accepts(field: Field) -> bool;
+
+    /// parses the value tokens of an entry, `None` if they are malformed
+    fn parse(field: Field, tokens: &[&str]) -> Option<Self>;
+
+    /// writes the value tokens of an entry
+    fn write<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+macro_rules! impl_value {
+    (complex => $($t:ty),*) => {$(
+        impl MatrixMarketValue for Complex<$t> {
+            const FIELD: Field = Field::Complex;
+
+            fn accepts(_: Field) -> bool {
+                true
+            }
+
+            fn parse(field: Field, tokens: &[&str]) -> Option<Self> {
+                match (field, tokens.len()) {
+                    (Field::Complex, 2) => Some(Complex::new(tokens[0].parse().ok()?, tokens[1].parse().ok()?)),
+                    _ => <$t as MatrixMarketValue>::parse(field, tokens).map(|re| Complex::new(re, <$t>::zero()))
+                }
+            }
+
+            fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                write!(writer, "{} {}", self.re, self.im)
+            }
+        }
+    )*};
+    ($field:ident => $($t:ty),*) => {$(
+        impl MatrixMarketValue for $t {
+            const FIELD: Field = Field::$field;
+
+            fn accepts(field: Field) -> bool {
+                match field {
+                    Field::Complex => false,
+                    Field::Real => Self::FIELD == Field::Real,
+                    Field::Integer | Field::Pattern => true
+                }
+            }
+
+            fn parse(field: Field, tokens: &[&str]) -> Option<Self> {
+                match (field, tokens.len()) {
+                    (Field::Pattern, 0) => Some(Self::one()),
+                    (Field::Real, 1) | (Field::Integer, 1) => tokens[0].parse().ok(),
+                    _ => None
+                }
+            }
+
+            fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                write!(writer, "{}", self)
+            }
+        }
+    )*};
+}
+
+impl_value!(Real => f32, f64);
+impl_value!(Integer => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_value!(complex => f32, f64);
+
+impl Header {
+    fn parse(line: &str) -> Result<Self, ParseErrorKind> {
+        let words: Vec<String> = line.split_whitespace().map(|w| w.to_lowercase()).collect();
+
+        if words.len() != 5 || words[0] != "%%matrixmarket" {
+            return Err(ParseErrorKind::Header)
+        }
+
+        if words[1] != "matrix" {
+            return Err(ParseErrorKind::Unsupported)
+        }
+
+        let format = match words[2].as_str() {
+            "coordinate" => Format::Coordinate,
+            "array" => Format::Array,
+            _ => return Err(ParseErrorKind::Unsupported)
+        };
+
+        let field = match words[3].as_str() {
+            "real" | "double" => Field::Real,
+            "integer" => Field::Integer,
+            "complex" => Field::Complex,
+            "pattern" if format == Format::Coordinate => Field::Pattern,
+            _ => return Err(ParseErrorKind::Unsupported)
+        };
+
+        let symmetry = match words[4].as_str() {
+            "general" => Symmetry::General,
+            "symmetric" => Symmetry::Symmetric,
+            _ => return Err(ParseErrorKind::Unsupported)
+        };
+
+        Ok(Header { format, field, symmetry })
+    }
+}
+
+// the lines of a file with their 1-based numbers
+struct Lines<R> {
+    lines: io::Lines<R>,
+    line: usize
+}
+
+impl<R: BufRead> Lines<R> {
+    fn next_raw(&mut self) -> Result<Option<String>, MatrixMarketError> {
+        match self.lines.next() {
+            Some(line) => {
+                self.line += 1;
+                Ok(Some(line?))
+            },
+            None => Ok(None)
+        }
+    }
+
+    // skips blank lines and comments
+    fn next(&mut self) -> Result<Option<String>, MatrixMarketError> {
+        while let Some(line) = self.next_raw()? {
+            let trimmed = line.trim();
+
+            if !trimmed.is_empty() && !trimmed.starts_with('%') {
+                return Ok(Some(line))
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> MatrixMarketError {
+        ParseError { line: self.line, kind }.into()
+    }
+}
+
+// the most entries reserved from the size line before any are read
+const MAX_RESERVED: usize = 1 << 16;
+
+// the entries of a file, with symmetric files already expanded
+struct Parsed<T> {
+    rows: usize,
+    cols: usize,
+    size_line: usize,
+    entries: Vec<(usize, usize, T)>
+}
+
+fn parse<T, R>(reader: R) -> Result<Parsed<T>, MatrixMarketError>
+    where T: MatrixMarketValue + Clone, R: BufRead {
+    let mut lines = Lines { lines: reader.lines(), line: 0 };
+
+    let header = lines.next_raw()?.ok_or(ParseError { line: 1, kind: ParseErrorKind::Header })?;
+    let header = Header::parse(&header).map_err(|kind| ParseError { line: 1, kind })?;
+
+    if !T::accepts(header.field) {
+        return Err(ParseError { line: 1, kind: ParseErrorKind::FieldMismatch }.into())
+    }
+
+    let size = lines.next()?.ok_or_else(|| lines.error(ParseErrorKind::Size))?;
+    let size = size.split_whitespace()
+                   .map(|s| s.parse())
+                   .collect::<Result<Vec<usize>, _>>()
+                   .map_err(|_| lines.error(ParseErrorKind::Size))?;
+    let symmetric = header.symmetry == Symmetry::Symmetric;
+
+    let count = match (header.format, size.len()) {
+        (Format::Coordinate, 3) => Some(size[2]),
+        (Format::Array, 2) if symmetric => size[0].checked_add(1).and_then(|n| n.checked_mul(size[0])).map(|n| n / 2),
+        (Format::Array, 2) => size[0].checked_mul(size[1]),
+        _ => None
+    }.ok_or_else(|| lines.error(ParseErrorKind::Size))?;
+
+    let (rows, cols, size_line) = (size[0], size[1], lines.line);
+
+    if symmetric && rows != cols {
+        return Err(lines.error(ParseErrorKind::NotSymmetric))
+    }
+
+    // the size line is not trusted to allocate up front, the rest grows as entries are read
+    let mut entries = Vec::with_capacity(count.min(MAX_RESERVED));
+    // the position of the next array entry, walking down the columns
+    let (mut row, mut col) = (0, 0);
+
+    for _ in 0..count {
+        let line = lines.next()?.ok_or_else(|| lines.error(ParseErrorKind::MissingEntries))?;
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+
+        let (i, j, values) = match header.format {
+            Format::Coordinate => {
+                if tokens.len() < 2 {
+                    return Err(lines.error(ParseErrorKind::Entry))
+                }
+
+                let i: usize = tokens[0].parse().map_err(|_| lines.error(ParseErrorKind::Entry))?;
+                let j: usize = tokens[1].parse().map_err(|_| lines.error(ParseErrorKind::Entry))?;
+
+                if i == 0 || j == 0 || i > rows || j > cols {
+                    return Err(lines.error(ParseErrorKind::OutOfBounds))
+                }
+
+                (i - 1, j - 1, &tokens[2..])
+            },
+            Format::Array => {
+                let position = (row, col);
+                row += 1;
+
+                if row == rows {
+                    col += 1;
+                    row = if symmetric { col } else { 0 };
+                }
+
+                (position.0, position.1, &tokens[..])
+            }
+        };
+
+        if symmetric && j > i {
+            return Err(lines.error(ParseErrorKind::NotSymmetric))
+        }
+
+        let value = T::parse(header.field, values).ok_or_else(|| lines.error(ParseErrorKind::Entry))?;
+
+        if symmetric && i != j {
+            entries.push((j, i, value.clone()));
+        }
+
+        entries.push((i, j, value));
+    }
+
+    if lines.next()?.is_some() {
+        return Err(lines.error(ParseErrorKind::ExtraEntries))
+    }
+
+    Ok(Parsed { rows, cols, size_line, entries })
+}
+
+impl<T> Parsed<T> {
+    // checks that `len` elements of `U` can be allocated before the result is built
+    fn allocate<U>(&self, len: Option<usize>) -> Result<(), MatrixMarketError> {
+        let bytes = len.and_then(|len| len.checked_mul(mem::size_of::<U>()));
+
+        match bytes {
+            Some(bytes) if bytes <= isize::max_value() as usize => Ok(()),
+            _ => Err(ParseError { line: self.size_line, kind: ParseErrorKind::TooLarge }.into())
+        }
+    }
+}
+
+/// reads a file in either format into a dense matrix, duplicate coordinate entries are summed
+pub fn read_matrix<T, R>(reader: R) -> Result<Matrix<T>, MatrixMarketError>
+    where T: InVector + MatrixMarketValue + Zero + Clone, R: BufRead {
+    let parsed = parse(reader)?;
+    parsed.allocate::<T>(parsed.rows.checked_mul(parsed.cols))?;
+
+    let mut matrix: Matrix<T> = Matrix::new(parsed.rows, parsed.cols);
+
+    for (i, j, v) in parsed.entries {
+        matrix[(i, j)] = matrix[(i, j)].clone() + v;
+    }
+
+    Ok(matrix)
+}
+
+// drops the stored zeros of array files
+fn to_coo<T: InVector + Zero>(parsed: Parsed<T>) -> CooMatrix<T> {
+    let mut coo = CooMatrix::with_capacity(parsed.rows, parsed.cols, parsed.entries.len());
+
+    for (i, j, v) in parsed.entries.into_iter().filter(|e| !e.2.is_zero()) {
+        coo.push(i, j, v);
+    }
+
+    coo
+}
+
+/// reads a file in either format into a coordinate matrix, zeros stored in array files are dropped
+pub fn read_coo<T, R>(reader: R) -> Result<CooMatrix<T>, MatrixMarketError>
+    where T: InVector + MatrixMarketValue + Zero + Clone, R: BufRead {
+    parse(reader).map(to_coo)
+}
+
+/// reads a file in either format into a compressed sparse row matrix
+pub fn read_csr<T, R>(reader: R) -> Result<CsrMatrix<T>, MatrixMarketError>
+    where T: InVector + MatrixMarketValue + Zero + Clone, R: BufRead {
+    let parsed = parse(reader)?;
+    parsed.allocate::<usize>(parsed.rows.checked_add(1))?;
+
+    Ok(to_coo(parsed).to_csr())
+}
+
+/// reads a file in either format into a compressed sparse column matrix
+pub fn read_csc<T, R>(reader: R) -> Result<CscMatrix<T>, MatrixMarketError>
+    where T: InVector + MatrixMarketValue + Zero + Clone, R: BufRead {
+    let parsed = parse(reader)?;
+    parsed.allocate::<usize>(parsed.cols.checked_add(1))?;
+
+    Ok(to_coo(parsed).to_csc())
+}
+
+/// reads a single column file in either format into a vector
+pub fn read_vector<T, R>(reader: R) -> Result<Vector<T>, MatrixMarketError>
+    where T: InVector + MatrixMarketValue + Zero + Clone, R: BufRead {
+    let parsed = parse(reader)?;
+
+    if parsed.cols != 1 {
+        return Err(ParseError { line: parsed.size_line, kind: ParseErrorKind::NotColumn }.into())
+    }
+
+    parsed.allocate::<T>(Some(parsed.rows))?;
+    let mut vector: Vector<T> = Vector::new(parsed.rows);
+
+    for (i, _, v) in parsed.entries {
+        vector[i] = vector[i].clone() + v;
+    }
+
+    Ok(vector)
+}
+
+fn write_header<W: Write>(writer: &mut W, format: Format, field: Field) -> io::Result<()> {
+    writeln!(writer, "{}", Header { format, field, symmetry: Symmetry::General })
+}
+
+fn write_coordinate<'a, T, W, I>(mut writer: W, (rows, cols): (usize, usize), nnz: usize, entries: I) -> io::Result<()>
+    where T: 'a + MatrixMarketValue, W: Write, I: Iterator<Item = (usize, usize, &'a T)> {
+    write_header(&mut writer, Format::Coordinate, T::FIELD)?;
+    writeln!(writer, "{} {} {}", rows, cols, nnz)?;
+
+    for (i, j, v) in entries {
+        write!(writer, "{} {} ", i + 1, j + 1)?;
+        v.write(&mut writer)?;
+        writer.write_all(b"\n")?;
+    }
+
+    Ok(())
+}
+
+/// writes a dense matrix in the general array format
+pub fn write_matrix<T, W>(mut writer: W, matrix: &Matrix<T>) -> io::Result<()>
+    where T: InVector + MatrixMarketValue, W: Write {
+    write_header(&mut writer, Format::Array, T::FIELD)?;
+    writeln!(writer, "{} {}", matrix.rows(), matrix.cols())?;
+
+    for j in 0..matrix.cols() {
+        for i in 0..matrix.rows() {
+            matrix[(i, j)].write(&mut writer)?;
+            writer.write_all(b"\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// writes a vector as a single column in the general array format
+pub fn write_vector<T, W>(mut writer: W, vector: &Vector<T>) -> io::Result<()>
+    where T: InVector + MatrixMarketValue, W: Write {
+    write_header(&mut writer, Format::Array, T::FIELD)?;
+    writeln!(writer, "{} 1", vector.dim())?;
+
+    for v in vector.iter() {
+        v.write(&mut writer)?;
+        writer.write_all(b"\n")?;
+    }
+
+    Ok(())
+}
+
+/// writes the stored entries of a coordinate matrix in the general coordinate format
+pub fn write_coo<T, W>(writer: W, matrix: &CooMatrix<T>) -> io::Result<()>
+    where T: InVector + MatrixMarketValue, W: Write {
+    write_coordinate(writer, matrix.dim(), matrix.nnz(), matrix.iter())
+}
+
+/// writes the stored entries of a compressed sparse row matrix in the general coordinate format
+pub fn write_csr<T, W>(writer: W, matrix: &CsrMatrix<T>) -> io::Result<()>
+    where T: InVector + MatrixMarketValue, W: Write {
+    write_coordinate(writer, matrix.dim(), matrix.nnz(), matrix.iter())
+}
+
+/// writes the stored entries of a compressed sparse column matrix in the general coordinate format
+pub fn write_csc<T, W>(writer: W, matrix: &CscMatrix<T>) -> io::Result<()>
+    where T: InVector + MatrixMarketValue, W: Write {
+    write_coordinate(writer, matrix.dim(), matrix.nnz(), matrix.iter())
+}
+
+impl fmt::Display for Header {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format = match self.format {
+            Format::Coordinate => "coordinate",
+            Format::Array => "array"
+        };
+
+        let field = match self.field {
+            Field::Real => "real",
+            Field::Integer => "integer",
+            Field::Complex => "complex",
+            Field::Pattern => "pattern"
+        };
+
+        let symmetry = match self.symmetry {
+            Symmetry::General => "general",
+            Symmetry::Symmetric => "symmetric"
+        };
+
+        write!(f, "%%MatrixMarket matrix {} {} {}", format, field, symmetry)
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match *self {
+            ParseErrorKind::Header => "expected a %%MatrixMarket header",
+            ParseErrorKind::Unsupported => "unsupported object, format, field or symmetry",
+            ParseErrorKind::FieldMismatch => "the field can not be read into the element type",
+            ParseErrorKind::Size => "malformed size line",
+            ParseErrorKind::Entry => "malformed entry",
+            ParseErrorKind::OutOfBounds => "entry index out of bounds",
+            ParseErrorKind::NotSymmetric => "symmetric matrix is not square or has an entry above the diagonal",
+            ParseErrorKind::MissingEntries => "fewer entries than announced by the size line",
+            ParseErrorKind::ExtraEntries => "more entries than announced by the size line",
+            ParseErrorKind::NotColumn => "expected a single column",
+            ParseErrorKind::TooLarge => "the size line is too large to allocate"
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+impl fmt::Display for MatrixMarketError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MatrixMarketError::Io(ref err) => write!(f, "{}", err),
+            MatrixMarketError::Parse(ref err) => write!(f, "{}", err)
+        }
+    }
+}
+
+impl ::std::error::Error for MatrixMarketError {}
+
+impl From<io::Error> for MatrixMarketError {
+    fn from(value: io::Error) -> Self {
+        MatrixMarketError::Io(value)
+    }
+}
+
+impl From<ParseError> for MatrixMarketError {
+    fn from(value: ParseError) -> Self {
+        MatrixMarketError::Parse(value)
+    }
+}
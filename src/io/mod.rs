@@ -0,0 +1,9 @@
+//! reading and writing vectors and matrices in file formats shared with other tools
+
+pub mod matrix_market;
+pub mod npy;
+#[cfg(feature = "npz")]
+pub mod npz;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,370 @@
+//! NumPy's `.npy` format, a magic string and version, a Python dict literal header describing
+//! the dtype, memory order and shape, then the raw element bytes
+
+use InVector;
+use matrix::Matrix;
+use vector::Vector;
+
+use std::prelude::v1::*;
+use std::io::{self, Read, Write};
+use std::fmt;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+// the total size of the preamble and header is padded to a multiple of this
+const ALIGN: usize = 64;
+
+// the longest header dict that is read, numpy refuses longer ones by default too
+const MAX_HEADER: usize = 10000;
+
+/// an element type that can be stored in `.npy` files
+pub trait NpyElement: Sized {
+    /// the dtype kind and size in bytes, like `f8`, without the byte order
+    const DTYPE: &'static str;
+
+    /// the size of an element in bytes
+    const SIZE: usize;
+
+    /// decodes an element from exactly `SIZE` bytes
+    fn from_bytes(bytes: &[u8], big_endian: bool) -> Self;
+
+    /// encodes the element as `SIZE` little endian bytes
+    fn to_bytes(&self) -> Vec<u8>;
+}
+
+macro_rules! impl_element {
+    ($($t:ty, $dtype:expr, $size:expr, |$b:ident| $from:expr, |$x:ident| $to:expr);* $(;)*) => {$(
+        impl NpyElement for $t {
+            const DTYPE: &'static str = $dtype;
+            const SIZE: usize = $size;
+
+            fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let fold = |acc: u64, &byte: &u8| acc << 8 | byte as u64;
+                let $b = if big_endian {
+                    bytes.iter().fold(0, fold)
+                } else {
+                    bytes.iter().rev().fold(0, fold)
+                };
+
+                $from
+            }
+
+            fn to_bytes(&self) -> Vec<u8> {
+                let $x = *self;
+                let bits: u64 = $to;
+
+                (0..$size).map(|i| (bits >> (8 * i)) as u8).collect()
+            }
+        }
+    )*};
+}
+
+impl_element! {
+    f32, "f4", 4, |b| f32::from_bits(b as u32), |x| x.to_bits() as u64;
+    f64, "f8", 8, |b| f64::from_bits(b), |x| x.to_bits();
+    i32, "i4", 4, |b| b as u32 as i32, |x| x as u32 as u64;
+    i64, "i8", 8, |b| b as i64, |x| x as u64;
+    u8, "u1", 1, |b| b as u8, |x| x as u64;
+    bool, "b1", 1, |b| b != 0, |x| x as u64;
+}
+
+/// errors produced while reading or writing `.npy` files and `.npz` archives
+#[derive(Debug)]
+pub enum NpyError {
+    Io(io::Error),
+    /// the data does not start with the `.npy` magic string
+    Magic,
+    /// the format version is not 1.0, 2.0 or 3.0
+    Version(u8, u8),
+    /// the header dict is malformed
+    Header,
+    /// the dtype of the array does not match the element type
+    DtypeMismatch { expected: &'static str, found: String },
+    /// the array has the wrong number of dimensions
+    Shape { expected: usize, found: Vec<usize> },
+    /// the archive is not a valid zip file
+    #[cfg(feature = "npz")]
+    Zip(::zip::result::ZipError),
+    /// the archive has no array with the name
+    #[cfg(feature = "npz")]
+    MissingArray(String)
+}
+
+/// the header of a `.npy` file
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Header {
+    /// the dtype with its byte order, like `<f8`
+    pub descr: String,
+    /// the data is in column-major instead of row-major order
+    pub fortran_order: bool,
+    pub shape: Vec<usize>
+}
+
+impl Header {
+    // splits a quoted string, a parenthesized tuple or a bare word off the front of `text`
+    fn token(text: &str) -> Result<(&str, &str), NpyError> {
+        let end = match text.chars().next() {
+            Some(quote @ '\'') | Some(quote @ '"') => text[1..].find(quote).map(|end| end + 2),
+            Some('(') => text.find(')').map(|end| end + 1),
+            Some(_) => Some(text.find(|c: char| c == ',' || c == ':' || c.is_whitespace()).unwrap_or(text.len())),
+            None => None
+        }.ok_or(NpyError::Header)?;
+
+        Ok((&text[..end], text[end..].trim_start()))
+    }
+
+    // the text between the quotes of a string literal
+    fn unquote(token: &str) -> Result<&str, NpyError> {
+        match token.chars().next() {
+            Some('\'') | Some('"') => Ok(&token[1..token.len() - 1]),
+            _ => Err(NpyError::Header)
+        }
+    }
+
+    fn parse(dict: &str) -> Result<Self, NpyError> {
+        let dict = dict.trim();
+
+        if !dict.starts_with('{') || !dict.ends_with('}') {
+            return Err(NpyError::Header)
+        }
+
+        let (mut descr, mut fortran_order, mut shape) = (None, None, None);
+        let mut rest = dict[1..dict.len() - 1].trim();
+
+        // the `key: value` pairs in order, with an optional trailing comma
+        while !rest.is_empty() {
+            let (key, after) = Self::token(rest)?;
+
+            if !after.starts_with(':') {
+                return Err(NpyError::Header)
+            }
+
+            let (value, after) = Self::token(after[1..].trim_start())?;
+
+            let slot = match Self::unquote(key)? {
+                "descr" => descr.replace(Self::unquote(value)?.to_string()).is_some(),
+                "fortran_order" => {
+                    let order = match value {
+                        "True" => true,
+                        "False" => false,
+                        _ => return Err(NpyError::Header)
+                    };
+
+                    fortran_order.replace(order).is_some()
+                },
+                "shape" => {
+                    if !value.starts_with('(') {
+                        return Err(NpyError::Header)
+                    }
+
+                    let dims = value[1..value.len() - 1].split(',')
+                                                        .map(|s| s.trim())
+                                                        .filter(|s| !s.is_empty())
+                                                        .map(|s| s.parse().map_err(|_| NpyError::Header))
+                                                        .collect::<Result<Vec<usize>, _>>()?;
+
+                    shape.replace(dims).is_some()
+                },
+                _ => true
+            };
+
+            // unknown or repeated keys
+            if slot {
+                return Err(NpyError::Header)
+            }
+
+            rest = if after.starts_with(',') {
+                after[1..].trim_start()
+            } else if after.is_empty() {
+                after
+            } else {
+                return Err(NpyError::Header)
+            };
+        }
+
+        match (descr, fortran_order, shape) {
+            (Some(descr), Some(fortran_order), Some(shape)) => Ok(Header { descr, fortran_order, shape }),
+            _ => Err(NpyError::Header)
+        }
+    }
+
+    /// reads the magic string, version and header of a `.npy` file
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, NpyError> {
+        let mut preamble = [0; 8];
+        reader.read_exact(&mut preamble)?;
+
+        if &preamble[..6] != MAGIC {
+            return Err(NpyError::Magic)
+        }
+
+        let len = match (preamble[6], preamble[7]) {
+            (1, 0) => {
+                let mut len = [0; 2];
+                reader.read_exact(&mut len)?;
+                len[0] as usize | (len[1] as usize) << 8
+            },
+            (2, 0) | (3, 0) => {
+                let mut len = [0; 4];
+                reader.read_exact(&mut len)?;
+                len.iter().rev().fold(0, |acc, &b| acc << 8 | b as usize)
+            },
+            (major, minor) => return Err(NpyError::Version(major, minor))
+        };
+
+        if len > MAX_HEADER {
+            return Err(NpyError::Header)
+        }
+
+        let mut dict = vec![0; len];
+        reader.read_exact(&mut dict)?;
+
+        Self::parse(&String::from_utf8_lossy(&dict))
+    }
+
+    /// writes the magic string, version and header, using version 2.0 only if the header does not fit 1.0
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let shape = match self.shape.len() {
+            1 => format!("({},)", self.shape[0]),
+            _ => format!("({})", self.shape.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(", "))
+        };
+
+        let order = if self.fortran_order { "True" } else { "False" };
+        let mut dict = format!("{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}", self.descr, order, shape);
+        let v1 = dict.len() + ALIGN <= u16::max_value() as usize;
+        let preamble = if v1 { 10 } else { 12 };
+
+        // pads with spaces so the data starts aligned, and ends with a newline
+        while (preamble + dict.len() + 1) % ALIGN != 0 {
+            dict.push(' ');
+        }
+        dict.push('\n');
+
+        let len = dict.len();
+        writer.write_all(MAGIC)?;
+
+        if v1 {
+            writer.write_all(&[1, 0, len as u8, (len >> 8) as u8])?;
+        } else {
+            writer.write_all(&[2, 0, len as u8, (len >> 8) as u8, (len >> 16) as u8, (len >> 24) as u8])?;
+        }
+
+        writer.write_all(dict.as_bytes())
+    }
+
+    // the byte order of the data, or an error if the dtype is not `T`'s
+    fn check<T: NpyElement>(&self) -> Result<bool, NpyError> {
+        let (order, kind) = match self.descr.chars().next() {
+            Some(c @ '<') | Some(c @ '>') | Some(c @ '|') | Some(c @ '=') => (c, &self.descr[1..]),
+            _ => ('=', &self.descr[..])
+        };
+
+        if kind != T::DTYPE {
+            return Err(NpyError::DtypeMismatch { expected: T::DTYPE, found: self.descr.clone() })
+        }
+
+        Ok(order == '>' || (order == '=' && cfg!(target_endian = "big")))
+    }
+
+    fn check_dims(&self, expected: usize) -> Result<(), NpyError> {
+        if self.shape.len() != expected {
+            return Err(NpyError::Shape { expected, found: self.shape.clone() })
+        }
+
+        Ok(())
+    }
+
+    fn for_data<T: NpyElement>(shape: Vec<usize>) -> Self {
+        let order = if T::SIZE == 1 { "|" } else { "<" };
+        Header { descr: format!("{}{}", order, T::DTYPE), fortran_order: false, shape }
+    }
+}
+
+// reads the data following a header
+fn read_data<T: NpyElement, R: Read>(reader: &mut R, header: &Header) -> Result<Vec<T>, NpyError> {
+    let big_endian = header.check::<T>()?;
+    let len = header.shape.iter()
+                          .try_fold(T::SIZE, |len, &dim| len.checked_mul(dim))
+                          .ok_or(NpyError::Header)?;
+
+    // the shape is not trusted to allocate up front, a short file ends the read instead
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+
+    if bytes.len() != len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into())
+    }
+
+    Ok(bytes.chunks(T::SIZE).map(|b| T::from_bytes(b, big_endian)).collect())
+}
+
+fn write_data<T: NpyElement, W: Write>(writer: &mut W, data: &[T]) -> io::Result<()> {
+    for x in data {
+        writer.write_all(&x.to_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// reads a 1-dimensional array into a vector
+pub fn read_vector<T, R>(mut reader: R) -> Result<Vector<T>, NpyError>
+    where T: InVector + NpyElement, R: Read {
+    let header = Header::read(&mut reader)?;
+    header.check_dims(1)?;
+
+    read_data(&mut reader, &header).map(Vector)
+}
+
+/// reads a 2-dimensional array in either memory order into a matrix
+pub fn read_matrix<T, R>(mut reader: R) -> Result<Matrix<T>, NpyError>
+    where T: InVector + NpyElement + Clone, R: Read {
+    let header = Header::read(&mut reader)?;
+    header.check_dims(2)?;
+
+    let (rows, cols) = (header.shape[0], header.shape[1]);
+    let data = read_data(&mut reader, &header)?;
+
+    if header.fortran_order {
+        Ok(Matrix::from_vec(cols, rows, data).transpose())
+    } else {
+        Ok(Matrix::from_vec(rows, cols, data))
+    }
+}
+
+/// writes a vector as a 1-dimensional little endian array
+pub fn write_vector<T, W>(mut writer: W, vector: &Vector<T>) -> io::Result<()>
+    where T: InVector + NpyElement, W: Write {
+    Header::for_data::<T>(vec![vector.dim()]).write(&mut writer)?;
+    write_data(&mut writer, &vector.0)
+}
+
+/// writes a matrix as a 2-dimensional little endian array in row-major order
+pub fn write_matrix<T, W>(mut writer: W, matrix: &Matrix<T>) -> io::Result<()>
+    where T: InVector + NpyElement, W: Write {
+    Header::for_data::<T>(vec![matrix.rows(), matrix.cols()]).write(&mut writer)?;
+    write_data(&mut writer, matrix.as_slice())
+}
+
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NpyError::Io(ref err) => write!(f, "{}", err),
+            NpyError::Magic => write!(f, "not a .npy file"),
+            NpyError::Version(major, minor) => write!(f, "unsupported .npy version {}.{}", major, minor),
+            NpyError::Header => write!(f, "malformed .npy header"),
+            NpyError::DtypeMismatch { expected, ref found } => write!(f, "expected dtype {}, found {}", expected, found),
+            NpyError::Shape { expected, ref found } => write!(f, "expected {} dimensions, found shape {:?}", expected, found),
+            #[cfg(feature = "npz")]
+            NpyError::Zip(ref err) => write!(f, "{}", err),
+            #[cfg(feature = "npz")]
+            NpyError::MissingArray(ref name) => write!(f, "no array named {}", name)
+        }
+    }
+}
+
+impl ::std::error::Error for NpyError {}
+
+impl From<io::Error> for NpyError {
+    fn from(value: io::Error) -> Self {
+        NpyError::Io(value)
+    }
+}
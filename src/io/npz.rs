@@ -0,0 +1,110 @@
+//! NumPy's `.npz` archives, a zip file holding one `<name>.npy` file per array
+
+use InVector;
+use matrix::Matrix;
+use vector::Vector;
+use super::npy::{self, NpyElement, NpyError};
+
+use std::prelude::v1::*;
+use std::io::{Read, Write, Seek};
+
+use zip::{ZipArchive, ZipWriter, CompressionMethod};
+use zip::write::FileOptions;
+use zip::result::ZipError;
+
+/// reads named arrays out of an `.npz` archive
+pub struct NpzReader<R: Read + Seek> {
+    archive: ZipArchive<R>
+}
+
+/// writes named arrays into an `.npz` archive
+pub struct NpzWriter<W: Write + Seek> {
+    writer: ZipWriter<W>,
+    compression: CompressionMethod
+}
+
+impl<R: Read + Seek> NpzReader<R> {
+    /// opens an archive written by `numpy.savez` or `numpy.savez_compressed`
+    pub fn new(reader: R) -> Result<Self, NpyError> {
+        Ok(NpzReader { archive: ZipArchive::new(reader)? })
+    }
+
+    /// the names of the arrays in the archive
+    pub fn names(&mut self) -> Result<Vec<String>, NpyError> {
+        (0..self.archive.len()).map(|i| {
+            let file = self.archive.by_index(i)?;
+            let name = file.name();
+
+            if name.ends_with(".npy") {
+                Ok(name[..name.len() - 4].to_string())
+            } else {
+                Ok(name.to_string())
+            }
+        }).collect()
+    }
+
+    /// reads the 1-dimensional array `name` into a vector
+    pub fn vector<T>(&mut self, name: &str) -> Result<Vector<T>, NpyError>
+        where T: InVector + NpyElement {
+        npy::read_vector(self.by_name(name)?)
+    }
+
+    /// reads the 2-dimensional array `name` into a matrix
+    pub fn matrix<T>(&mut self, name: &str) -> Result<Matrix<T>, NpyError>
+        where T: InVector + NpyElement + Clone {
+        npy::read_matrix(self.by_name(name)?)
+    }
+
+    fn by_name(&mut self, name: &str) -> Result<::zip::read::ZipFile<'_>, NpyError> {
+        match self.archive.by_name(&format!("{}.npy", name)) {
+            Err(ZipError::FileNotFound) => Err(NpyError::MissingArray(name.to_string())),
+            result => result.map_err(NpyError::from)
+        }
+    }
+}
+
+impl<W: Write + Seek> NpzWriter<W> {
+    /// creates an uncompressed archive, like `numpy.savez`
+    pub fn new(writer: W) -> Self {
+        NpzWriter { writer: ZipWriter::new(writer), compression: CompressionMethod::Stored }
+    }
+
+    /// creates a deflate compressed archive, like `numpy.savez_compressed`
+    pub fn new_compressed(writer: W) -> Self {
+        NpzWriter { writer: ZipWriter::new(writer), compression: CompressionMethod::Deflated }
+    }
+
+    fn start(&mut self, name: &str) -> Result<(), NpyError> {
+        let options = FileOptions::default().compression_method(self.compression);
+        self.writer.start_file(format!("{}.npy", name), options)?;
+        Ok(())
+    }
+
+    /// adds a vector as the 1-dimensional array `name`
+    pub fn add_vector<T>(&mut self, name: &str, vector: &Vector<T>) -> Result<(), NpyError>
+        where T: InVector + NpyElement {
+        self.start(name)?;
+        Ok(npy::write_vector(&mut self.writer, vector)?)
+    }
+
+    /// adds a matrix as the 2-dimensional array `name`
+    pub fn add_matrix<T>(&mut self, name: &str, matrix: &Matrix<T>) -> Result<(), NpyError>
+        where T: InVector + NpyElement {
+        self.start(name)?;
+        Ok(npy::write_matrix(&mut self.writer, matrix)?)
+    }
+
+    /// writes the archive's directory, returning the underlying writer
+    pub fn finish(mut self) -> Result<W, NpyError> {
+        Ok(self.writer.finish()?)
+    }
+}
+
+impl From<ZipError> for NpyError {
+    fn from(value: ZipError) -> Self {
+        match value {
+            ZipError::Io(err) => NpyError::Io(err),
+            err => NpyError::Zip(err)
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use super::matrix_market::*;
+use super::npy;
+use matrix::Matrix;
+use sparse::CsrMatrix;
+use vector::Vector;
+
+use std::prelude::v1::*;
+
+use num::complex::Complex;
+
+type MatrixD = Matrix<f64>;
+
+fn parse_error<T: ::std::fmt::Debug>(result: Result<T, MatrixMarketError>) -> ParseError {
+    match result {
+        Err(MatrixMarketError::Parse(err)) => err,
+        other => panic!("expected a parse error, found {:?}", other)
+    }
+}
+
+#[test]
+fn read_coordinate() {
+    let text = "%%MatrixMarket matrix coordinate real general\n\
+                % a comment\n\
+                \n\
+                2 3 3\n\
+                1 1 1.5\n\
+                2 3 -2\n\
+                1 1 0.5\n";
+
+    let dense: MatrixD = read_matrix(text.as_bytes()).unwrap();
+    assert_eq!(dense, MatrixD::from_vec(2, 3, vec![2.0, 0.0, 0.0, 0.0, 0.0, -2.0]));
+
+    let csr: CsrMatrix<f64> = read_csr(text.as_bytes()).unwrap();
+    assert_eq!(csr.to_dense(), dense)
+}
+
+#[test]
+fn read_symmetric_and_pattern() {
+    let symmetric = "%%MatrixMarket matrix coordinate integer symmetric\n3 3 3\n1 1 4\n3 1 -1\n2 2 5\n";
+    let m: Matrix<i32> = read_matrix(symmetric.as_bytes()).unwrap();
+    assert_eq!(m, Matrix::from_vec(3, 3, vec![4, 0, -1, 0, 5, 0, -1, 0, 0]));
+
+    let array = "%%MatrixMarket matrix array real symmetric\n2 2\n1\n2\n3\n";
+    let m: MatrixD = read_matrix(array.as_bytes()).unwrap();
+    assert_eq!(m, MatrixD::from_vec(2, 2, vec![1.0, 2.0, 2.0, 3.0]));
+
+    let pattern = "%%MatrixMarket matrix coordinate pattern general\n2 2 2\n1 2\n2 1\n";
+    let m: Matrix<u8> = read_matrix(pattern.as_bytes()).unwrap();
+    assert_eq!(m, Matrix::from_vec(2, 2, vec![0, 1, 1, 0]))
+}
+
+#[test]
+fn read_complex_and_vector() {
+    let complex = "%%MatrixMarket matrix array complex general\n1 2\n1 -1\n0 2.5\n";
+    let m: Matrix<Complex<f64>> = read_matrix(complex.as_bytes()).unwrap();
+    assert_eq!(m.into_vec(), vec![Complex::new(1.0, -1.0), Complex::new(0.0, 2.5)]);
+
+    let column = "%%MatrixMarket matrix coordinate real general\n4 1 2\n2 1 3\n4 1 1e-3\n";
+    let v: Vector<f64> = read_vector(column.as_bytes()).unwrap();
+    assert_eq!(v, Vector::from(vec![0.0, 3.0, 0.0, 1e-3]));
+
+    let wide = "%%MatrixMarket matrix array real general\n1 2\n1\n2\n";
+    assert_eq!(parse_error(read_vector::<f64, _>(wide.as_bytes())), ParseError { line: 2, kind: ParseErrorKind::NotColumn })
+}
+
+#[test]
+fn parse_errors() {
+    let cases = vec![
+        ("", 1, ParseErrorKind::Header),
+        ("%%MatrixMarket matrix coordinate real hermitian\n", 1, ParseErrorKind::Unsupported),
+        ("%%MatrixMarket matrix array pattern general\n", 1, ParseErrorKind::Unsupported),
+        ("%%MatrixMarket matrix coordinate complex general\n1 1 0\n", 1, ParseErrorKind::FieldMismatch),
+        ("%%MatrixMarket matrix coordinate real general\n% size\n2 x 1\n", 3, ParseErrorKind::Size),
+        ("%%MatrixMarket matrix array real general\n4294967296 4294967297\n", 2, ParseErrorKind::Size),
+        ("%%MatrixMarket matrix array real symmetric\n18446744073709551615 18446744073709551615\n", 2, ParseErrorKind::Size),
+        ("%%MatrixMarket matrix coordinate real general\n2 2 18446744073709551616\n", 2, ParseErrorKind::Size),
+        ("%%MatrixMarket matrix coordinate real general\n2 2 18446744073709551615\n1 1 1\n", 3, ParseErrorKind::MissingEntries),
+        ("%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n1 two 1\n", 4, ParseErrorKind::Entry),
+        ("%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1\n", 3, ParseErrorKind::OutOfBounds),
+        ("%%MatrixMarket matrix coordinate real symmetric\n2 2 1\n1 2 1\n", 3, ParseErrorKind::NotSymmetric),
+        ("%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n", 3, ParseErrorKind::MissingEntries),
+        ("%%MatrixMarket matrix array real general\n1 1\n1\n2\n", 4, ParseErrorKind::ExtraEntries)
+    ];
+
+    for (text, line, kind) in cases {
+        assert_eq!(parse_error(read_matrix::<f64, _>(text.as_bytes())), ParseError { line, kind }, "{:?}", text);
+    }
+
+    // sizes that fit the size line but not memory are reported instead of panicking while allocating
+    let huge = "%%MatrixMarket matrix coordinate real general\n18446744073709551615 1 0\n";
+    let too_large = ParseError { line: 2, kind: ParseErrorKind::TooLarge };
+    assert_eq!(parse_error(read_matrix::<f64, _>(huge.as_bytes())), too_large);
+    assert_eq!(parse_error(read_vector::<f64, _>(huge.as_bytes())), too_large);
+    assert_eq!(parse_error(read_csr::<f64, _>(huge.as_bytes())), too_large);
+    assert_eq!(read_csc::<f64, _>(huge.as_bytes()).unwrap().nnz(), 0);
+
+    let wide = "%%MatrixMarket matrix coordinate real general\n% square\n4294967296 4294967296 0\n";
+    let too_large = ParseError { line: 3, kind: ParseErrorKind::TooLarge };
+    assert_eq!(parse_error(read_matrix::<f64, _>(wide.as_bytes())), too_large);
+    assert_eq!(parse_error(read_csr::<f64, _>("%%MatrixMarket matrix coordinate real general\n18446744073709551615 2 0\n".as_bytes())).kind, ParseErrorKind::TooLarge);
+    assert_eq!(parse_error(read_csc::<f64, _>("%%MatrixMarket matrix coordinate real general\n2 18446744073709551615 0\n".as_bytes())).kind, ParseErrorKind::TooLarge);
+
+    let integer = "%%MatrixMarket matrix array real general\n1 1\n1.5\n";
+    assert_eq!(parse_error(read_matrix::<i32, _>(integer.as_bytes())).kind, ParseErrorKind::FieldMismatch)
+}
+
+#[test]
+fn write_round_trip() {
+    let dense = MatrixD::from_vec(2, 3, vec![1.0, 0.0, -2.5, 0.0, 3.0, 0.125]);
+    let mut out = Vec::new();
+    write_matrix(&mut out, &dense).unwrap();
+
+    assert!(out.starts_with(b"%%MatrixMarket matrix array real general\n2 3\n1\n0\n0\n3\n"));
+    assert_eq!(read_matrix::<f64, _>(&out[..]).unwrap(), dense);
+
+    let csr = CsrMatrix::from(&dense);
+    let mut out = Vec::new();
+    write_csr(&mut out, &csr).unwrap();
+
+    assert_eq!(String::from_utf8(out.clone()).unwrap(),
+               "%%MatrixMarket matrix coordinate real general\n2 3 4\n1 1 1\n1 3 -2.5\n2 2 3\n2 3 0.125\n");
+    assert_eq!(read_csr::<f64, _>(&out[..]).unwrap(), csr);
+
+    let mut out = Vec::new();
+    write_csc(&mut out, &csr.to_csc()).unwrap();
+    assert_eq!(read_csr::<f64, _>(&out[..]).unwrap(), csr);
+
+    let v = Vector::from(vec![Complex::new(1.0f32, 2.0), Complex::new(-0.5, 0.0)]);
+    let mut out = Vec::new();
+    write_vector(&mut out, &v).unwrap();
+
+    assert_eq!(String::from_utf8(out.clone()).unwrap(), "%%MatrixMarket matrix array complex general\n2 1\n1 2\n-0.5 0\n");
+    assert_eq!(read_vector::<Complex<f32>, _>(&out[..]).unwrap(), v)
+}
+
+// a .npy file with the given version and header dict, padded like numpy pads it
+fn npy_file(major: u8, dict: &str, data: &[u8]) -> Vec<u8> {
+    let preamble = if major == 1 { 10 } else { 12 };
+    let mut dict = dict.to_string();
+
+    while (preamble + dict.len() + 1) % 64 != 0 {
+        dict.push(' ');
+    }
+    dict.push('\n');
+
+    let mut file = b"\x93NUMPY".to_vec();
+    file.extend_from_slice(&[major, 0, dict.len() as u8, (dict.len() >> 8) as u8]);
+
+    if major != 1 {
+        file.extend_from_slice(&[0, 0]);
+    }
+
+    file.extend_from_slice(dict.as_bytes());
+    file.extend_from_slice(data);
+    file
+}
+
+#[test]
+fn npy_read() {
+    let little = npy_file(1, "{'descr': '<i4', 'fortran_order': False, 'shape': (3,), }", &[1, 0, 0, 0, 0xfe, 0xff, 0xff, 0xff, 0, 1, 0, 0]);
+    assert_eq!(npy::read_vector::<i32, _>(&little[..]).unwrap(), Vector::from(vec![1, -2, 256]));
+
+    let big = npy_file(2, "{'descr': '>f8', 'fortran_order': False, 'shape': (1,), }", &[0x3f, 0xf8, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(npy::read_vector::<f64, _>(&big[..]).unwrap(), Vector::from(vec![1.5]));
+
+    let fortran = npy_file(1, "{'descr': '|u1', 'fortran_order': True, 'shape': (2, 3), }", &[1, 4, 2, 5, 3, 6]);
+    assert_eq!(npy::read_matrix::<u8, _>(&fortran[..]).unwrap(), Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]));
+
+    let flags = npy_file(3, "{\"shape\": (2,), \"fortran_order\": False, \"descr\": \"|b1\"}", &[1, 0]);
+    assert_eq!(npy::read_vector::<bool, _>(&flags[..]).unwrap(), Vector::from(vec![true, false]))
+}
+
+#[test]
+fn npy_errors() {
+    let file = npy_file(1, "{'descr': '<f4', 'fortran_order': False, 'shape': (2, 2), }", &[0; 16]);
+
+    match npy::read_matrix::<f64, _>(&file[..]) {
+        Err(npy::NpyError::DtypeMismatch { expected, ref found }) => assert_eq!((expected, found.as_str()), ("f8", "<f4")),
+        other => panic!("expected a dtype mismatch, found {:?}", other)
+    }
+
+    match npy::read_vector::<f32, _>(&file[..]) {
+        Err(npy::NpyError::Shape { expected, ref found }) => assert_eq!((expected, found.clone()), (1, vec![2, 2])),
+        other => panic!("expected a shape mismatch, found {:?}", other)
+    }
+
+    match npy::read_matrix::<f32, _>(&file[..file.len() - 1]) {
+        Err(npy::NpyError::Io(ref err)) => assert_eq!(err.kind(), ::std::io::ErrorKind::UnexpectedEof),
+        other => panic!("expected truncated data, found {:?}", other)
+    }
+
+    assert!(match npy::read_vector::<f32, _>(&b"PK\x03\x04 not npy"[..]) { Err(npy::NpyError::Magic) => true, _ => false });
+    assert!(match npy::read_vector::<f32, _>(&npy_file(1, "{'descr': '<f4'}", &[])[..]) { Err(npy::NpyError::Header) => true, _ => false })
+}
+
+#[test]
+fn npy_headers() {
+    let reordered = npy_file(1, "{'shape': (2,), 'descr': '<i4', 'fortran_order': False}", &[7, 0, 0, 0, 9, 0, 0, 0]);
+    assert_eq!(npy::read_vector::<i32, _>(&reordered[..]).unwrap(), Vector::from(vec![7, 9]));
+
+    let dicts = vec![
+        "{'descr': '<f4', 'fortran_order': False, 'shape': (2,",
+        "{'descr': '<f4', 'fortran_order': False, 'shape': (2,)",
+        "{'descr': \"'shape': (1,)\", 'fortran_order': False}",
+        "{'descr': '<f4', 'fortran_order': Maybe, 'shape': (2,)}",
+        "{'descr': '<f4', 'fortran_order': False 'shape': (2,)}",
+        "{'descr': '<f4', 'descr': '<f4', 'fortran_order': False, 'shape': (2,)}",
+        "{'descr': '<f4', 'fortran_order': False, 'shape': (2,), 'extra': 1}",
+        "{'descr': 'é', 'fortran_order': False, 'shape': (x,)}",
+        "{'désc': '<f4', 'fortran_order': False, 'shape': (2,)}",
+        "{'descr'}",
+        "not a dict"
+    ];
+
+    for dict in dicts {
+        let file = npy_file(1, dict, &[0; 8]);
+        assert!(match npy::read_vector::<f32, _>(&file[..]) { Err(npy::NpyError::Header) => true, _ => false }, "{:?}", dict);
+    }
+
+    // shapes whose byte size overflows and headers longer than numpy allows are rejected before allocating
+    let huge = npy_file(1, "{'descr': '<f8', 'fortran_order': False, 'shape': (4611686018427387904, 4), }", &[]);
+    assert!(match npy::read_matrix::<f64, _>(&huge[..]) { Err(npy::NpyError::Header) => true, _ => false });
+
+    let long = b"\x93NUMPY\x02\x00\xff\xff\xff\xff{".to_vec();
+    assert!(match npy::read_vector::<f64, _>(&long[..]) { Err(npy::NpyError::Header) => true, _ => false });
+
+    let short = npy_file(1, "{'descr': '<f8', 'fortran_order': False, 'shape': (1000000000,), }", &[0; 16]);
+    assert!(match npy::read_vector::<f64, _>(&short[..]) { Err(npy::NpyError::Io(ref err)) => err.kind() == ::std::io::ErrorKind::UnexpectedEof, _ => false })
+}
+
+#[test]
+fn npy_round_trip() {
+    let m = Matrix::from_vec(2, 2, vec![1i64, -2, 3, i64::max_value()]);
+    let mut out = Vec::new();
+    npy::write_matrix(&mut out, &m).unwrap();
+
+    assert_eq!(out.len() % 64, 32);
+    assert!(out.starts_with(b"\x93NUMPY\x01\x00\x76\x00{'descr': '<i8', 'fortran_order': False, 'shape': (2, 2), }"));
+    assert_eq!(npy::read_matrix::<i64, _>(&out[..]).unwrap(), m);
+
+    let v = Vector::from(vec![0.25f32, -1.0, 3.5]);
+    let mut out = Vec::new();
+    npy::write_vector(&mut out, &v).unwrap();
+
+    assert!(out.starts_with(b"\x93NUMPY\x01\x00\x76\x00{'descr': '<f4', 'fortran_order': False, 'shape': (3,), }"));
+    assert_eq!(npy::read_vector::<f32, _>(&out[..]).unwrap(), v)
+}
+
+#[cfg(feature = "npz")]
+#[test]
+fn npz_round_trip() {
+    use super::npz::{NpzReader, NpzWriter};
+    use std::io::Cursor;
+
+    for &compressed in &[false, true] {
+        let (v, m) = (Vector::from(vec![1.0, 2.0, 3.0]), Matrix::from_vec(2, 2, vec![true, false, false, true]));
+        let mut writer = if compressed { NpzWriter::new_compressed(Cursor::new(Vec::new())) } else { NpzWriter::new(Cursor::new(Vec::new())) };
+        writer.add_vector("x", &v).unwrap();
+        writer.add_matrix("mask", &m).unwrap();
+
+        let mut reader = NpzReader::new(writer.finish().unwrap()).unwrap();
+        assert_eq!(reader.names().unwrap(), vec!["x", "mask"]);
+        assert_eq!(reader.vector::<f64>("x").unwrap(), v);
+        assert_eq!(reader.matrix::<bool>("mask").unwrap(), m);
+        assert!(match reader.vector::<f64>("y") { Err(npy::NpyError::MissingArray(ref name)) => name == "y", _ => false });
+        assert!(match reader.vector::<f32>("x") { Err(npy::NpyError::DtypeMismatch { .. }) => true, _ => false })
+    }
+}
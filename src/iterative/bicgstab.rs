@@ -0,0 +1,72 @@
+use super::{LinearOperator, Preconditioner, Identity, SolverOptions, Solution, InVector};
+use super::{check, axpy, scale_add};
+use matrix::MatrixError;
+use vector::Vector;
+
+use num::traits::*;
+
+/// solves `A * x = b` with the stabilized biconjugate gradient method, `A` can be nonsymmetric
+pub fn bicgstab<T, A>(a: &A, b: &Vector<T>, options: SolverOptions<T>) -> Result<Solution<T>, MatrixError>
+    where T: InVector + Float, A: LinearOperator<T> + ?Sized {
+    bicgstab_with(a, b, &Identity, options)
+}
+
+/// solves `A * x = b` with the right preconditioned stabilized biconjugate gradient method
+pub fn bicgstab_with<T, A, P>(a: &A, b: &Vector<T>, precond: &P, options: SolverOptions<T>) -> Result<Solution<T>, MatrixError>
+    where T: InVector + Float, A: LinearOperator<T> + ?Sized, P: Preconditioner<T> + ?Sized {
+    check(a, b)?;
+
+    let b_norm = b.mag();
+    let mut x = Vector::new(b.dim());
+
+    if b_norm.is_zero() {
+        return Ok(Solution { x, iterations: 0, residual: T::zero() })
+    }
+
+    let mut r = b.clone();
+    let r_hat = b.clone();
+    let mut p: Vector<T> = Vector::new(b.dim());
+    let mut v: Vector<T> = Vector::new(b.dim());
+    let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+
+    for k in 1..=options.max_iter {
+        let rho_next: T = r_hat.dot(&r);
+
+        // the shadow residual became orthogonal to the residual
+        if rho_next.is_zero() || omega.is_zero() {
+            return Err(MatrixError::NoConvergence { iterations: k })
+        }
+
+        let beta = (rho_next / rho) * (alpha / omega);
+        axpy(&mut p, -omega, &v);
+        p = scale_add(&p, beta, &r);
+
+        let p_hat = precond.apply(&p);
+        v = a.apply(&p_hat)?;
+        alpha = rho_next / r_hat.dot(&v);
+        rho = rho_next;
+
+        let s = scale_add(&v, -alpha, &r);
+        axpy(&mut x, alpha, &p_hat);
+
+        let residual = s.mag() / b_norm;
+        if residual <= options.tol {
+            return Ok(Solution { x, iterations: k, residual })
+        }
+
+        let s_hat = precond.apply(&s);
+        let t = a.apply(&s_hat)?;
+        let tt: T = t.dot(&t);
+        omega = if tt.is_zero() { T::zero() } else { t.dot(&s) / tt };
+
+        axpy(&mut x, omega, &s_hat);
+        r = scale_add(&t, -omega, &s);
+
+        let residual = r.mag() / b_norm;
+        if residual <= options.tol {
+            return Ok(Solution { x, iterations: k, residual })
+        }
+    }
+
+    Err(MatrixError::NoConvergence { iterations: options.max_iter })
+}
@@ -0,0 +1,56 @@
+use super::{LinearOperator, Preconditioner, Identity, SolverOptions, Solution, InVector};
+use super::{check, axpy, scale_add};
+use matrix::MatrixError;
+use vector::Vector;
+
+use num::traits::*;
+
+/// solves `A * x = b` with the conjugate gradient method, `A` must be symmetric positive definite
+pub fn cg<T, A>(a: &A, b: &Vector<T>, options: SolverOptions<T>) -> Result<Solution<T>, MatrixError>
+    where T: InVector + Float, A: LinearOperator<T> + ?Sized {
+    cg_with(a, b, &Identity, options)
+}
+
+/// solves `A * x = b` with the preconditioned conjugate gradient method,
+/// both `A` and the preconditioner must be symmetric positive definite
+pub fn cg_with<T, A, P>(a: &A, b: &Vector<T>, precond: &P, options: SolverOptions<T>) -> Result<Solution<T>, MatrixError>
+    where T: InVector + Float, A: LinearOperator<T> + ?Sized, P: Preconditioner<T> + ?Sized {
+    check(a, b)?;
+
+    let b_norm = b.mag();
+    let mut x = Vector::new(b.dim());
+
+    if b_norm.is_zero() {
+        return Ok(Solution { x, iterations: 0, residual: T::zero() })
+    }
+
+    let mut r = b.clone();
+    let mut p = precond.apply(&r);
+    let mut rz: T = r.dot(&p);
+
+    for k in 1..=options.max_iter {
+        let q = a.apply(&p)?;
+        let pq: T = p.dot(&q);
+
+        // the operator is not positive definite along `p`
+        if !(pq > T::zero()) {
+            return Err(MatrixError::NoConvergence { iterations: k })
+        }
+
+        let alpha = rz / pq;
+        axpy(&mut x, alpha, &p);
+        axpy(&mut r, -alpha, &q);
+
+        let residual = r.mag() / b_norm;
+        if residual <= options.tol {
+            return Ok(Solution { x, iterations: k, residual })
+        }
+
+        let z = precond.apply(&r);
+        let rz_next: T = r.dot(&z);
+        p = scale_add(&p, rz_next / rz, &z);
+        rz = rz_next;
+    }
+
+    Err(MatrixError::NoConvergence { iterations: options.max_iter })
+}
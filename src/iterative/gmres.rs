@@ -0,0 +1,120 @@
+use super::{LinearOperator, Preconditioner, Identity, SolverOptions, Solution, InVector};
+use super::{check, axpy};
+use matrix::MatrixError;
+use vector::Vector;
+
+use std::prelude::v1::*;
+
+use num::traits::*;
+
+/// the default number of iterations between restarts of `gmres`
+pub const DEFAULT_RESTART: usize = 30;
+
+/// solves `A * x = b` with the restarted generalized minimal residual method, `A` can be nonsymmetric,
+/// the Krylov basis is thrown away every `restart` iterations to bound memory use
+pub fn gmres<T, A>(a: &A, b: &Vector<T>, restart: usize, options: SolverOptions<T>) -> Result<Solution<T>, MatrixError>
+    where T: InVector + Float, A: LinearOperator<T> + ?Sized {
+    gmres_with(a, b, &Identity, restart, options)
+}
+
+/// solves `A * x = b` with the right preconditioned restarted generalized minimal residual method
+pub fn gmres_with<T, A, P>(a: &A, b: &Vector<T>, precond: &P, restart: usize, options: SolverOptions<T>) -> Result<Solution<T>, MatrixError>
+    where T: InVector + Float, A: LinearOperator<T> + ?Sized, P: Preconditioner<T> + ?Sized {
+    check(a, b)?;
+    assert!(restart > 0, "gmres needs at least one iteration per restart");
+
+    let b_norm = b.mag();
+    let mut x = Vector::new(b.dim());
+
+    if b_norm.is_zero() {
+        return Ok(Solution { x, iterations: 0, residual: T::zero() })
+    }
+
+    let mut r = b.clone();
+    let mut iterations = 0;
+
+    while iterations < options.max_iter {
+        let beta = r.mag();
+
+        // the orthonormal Krylov basis, and the Hessenberg matrix stored by columns
+        // after being reduced to upper triangular form by Givens rotations
+        let mut basis = vec![&r / beta];
+        let mut h: Vec<Vec<T>> = Vec::with_capacity(restart);
+        let mut rotations: Vec<(T, T)> = Vec::with_capacity(restart);
+        let mut g = vec![beta];
+
+        while h.len() < restart && iterations < options.max_iter {
+            iterations += 1;
+
+            let j = h.len();
+            let mut w = a.apply(&precond.apply(&basis[j]))?;
+            let mut col = Vec::with_capacity(j + 2);
+
+            // modified Gram-Schmidt
+            for v in basis.iter() {
+                let hij: T = w.dot(v);
+                axpy(&mut w, -hij, v);
+                col.push(hij);
+            }
+
+            let w_norm = w.mag();
+            col.push(w_norm);
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (p, q) = (col[i], col[i + 1]);
+                col[i] = c * p + s * q;
+                col[i + 1] = c * q - s * p;
+            }
+
+            let d = col[j].hypot(col[j + 1]);
+
+            if d.is_zero() {
+                return Err(MatrixError::Singular)
+            }
+
+            let (c, s) = (col[j] / d, col[j + 1] / d);
+            col[j] = d;
+            col.pop();
+            rotations.push((c, s));
+            h.push(col);
+
+            g.push(-s * g[j]);
+            g[j] = c * g[j];
+
+            // the Krylov space is invariant, so the current solution is exact
+            if w_norm.is_zero() || g[j + 1].abs() <= options.tol * b_norm {
+                break
+            }
+
+            basis.push(w / w_norm);
+        }
+
+        // back substitution for the least squares coefficients
+        let k = h.len();
+        let mut y = g;
+        y.truncate(k);
+
+        for i in (0..k).rev() {
+            for l in i + 1..k {
+                y[i] = y[i] - h[l][i] * y[l];
+            }
+            y[i] = y[i] / h[i][i];
+        }
+
+        let mut update = Vector::new(b.dim());
+        for (v, &y) in basis.iter().zip(y.iter()) {
+            axpy(&mut update, y, v);
+        }
+        axpy(&mut x, T::one(), &precond.apply(&update));
+
+        let ax = a.apply(&x)?;
+        r = b - &ax;
+
+        let residual = r.mag() / b_norm;
+        if residual <= options.tol {
+            return Ok(Solution { x, iterations, residual })
+        }
+    }
+
+    Err(MatrixError::NoConvergence { iterations: options.max_iter })
+}
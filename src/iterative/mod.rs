@@ -0,0 +1,106 @@
+//! iterative solvers for large, usually sparse, square systems `A * x = b`, the matrix is
+//! only ever touched through `LinearOperator::apply`, so it never has to be factorized
+
+pub mod operator;
+pub mod precond;
+pub mod cg;
+pub mod bicgstab;
+pub mod gmres;
+
+#[cfg(test)]
+mod tests;
+
+use super::InVector;
+use matrix::{MatrixError, ShapeMismatch};
+use vector::Vector;
+
+use num::traits::*;
+
+/// the default iteration limit of `SolverOptions`
+pub const DEFAULT_MAX_ITER: usize = 1000;
+
+/// anything that can be multiplied with a vector, like a dense or sparse matrix
+pub trait LinearOperator<T: InVector> {
+    /// the shape of the operator as `(rows, cols)`
+    fn dim(&self) -> (usize, usize);
+
+    /// computes `A * x`
+    fn apply(&self, x: &Vector<T>) -> Result<Vector<T>, ShapeMismatch>;
+}
+
+/// an approximation of the inverse of a system's matrix, used to speed up convergence
+pub trait Preconditioner<T: InVector> {
+    /// approximately solves `M * z = r` for `z`
+    fn apply(&self, r: &Vector<T>) -> Vector<T>;
+}
+
+/// the preconditioner that does nothing, used when a solver is run without one
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Identity;
+
+impl<T: InVector + Clone> Preconditioner<T> for Identity {
+    fn apply(&self, r: &Vector<T>) -> Vector<T> {
+        r.clone()
+    }
+}
+
+/// when the iterative solvers stop
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SolverOptions<T> {
+    /// the solver stops once `|b - A * x| <= tol * |b|`
+    pub tol: T,
+    /// the number of iterations before giving up with `MatrixError::NoConvergence`
+    pub max_iter: usize
+}
+
+impl<T> SolverOptions<T> {
+    pub fn new(tol: T, max_iter: usize) -> Self {
+        SolverOptions { tol, max_iter }
+    }
+}
+
+impl<T: Float> Default for SolverOptions<T> {
+    // converges to about half of the available precision
+    fn default() -> Self {
+        SolverOptions { tol: T::epsilon().sqrt(), max_iter: DEFAULT_MAX_ITER }
+    }
+}
+
+/// the result of a converged iterative solve
+#[derive(Clone, PartialEq, Debug)]
+pub struct Solution<T: InVector> {
+    /// the approximate solution
+    pub x: Vector<T>,
+    /// the number of iterations taken
+    pub iterations: usize,
+    /// the final residual norm relative to `b`, `|b - A * x| / |b|`
+    pub residual: T
+}
+
+// checks that `a` is square and matches `b`
+fn check<T, A>(a: &A, b: &Vector<T>) -> Result<(), MatrixError>
+    where T: InVector, A: LinearOperator<T> + ?Sized {
+    let (rows, cols) = a.dim();
+
+    if rows != cols {
+        return Err(MatrixError::NotSquare { rows, cols })
+    }
+
+    if cols != b.dim() {
+        return Err(ShapeMismatch { left: (rows, cols), right: (b.dim(), 1) }.into())
+    }
+
+    Ok(())
+}
+
+// y += alpha * x
+fn axpy<T: InVector + Float>(y: &mut Vector<T>, alpha: T, x: &Vector<T>) {
+    y.iter_mut()
+     .zip(x.iter())
+     .for_each(|(y, &x)| *y = *y + alpha * x)
+}
+
+// x * alpha + y
+fn scale_add<T: InVector + Float>(x: &Vector<T>, alpha: T, y: &Vector<T>) -> Vector<T> {
+    Vector(x.iter().zip(y.iter()).map(|(&x, &y)| x * alpha + y).collect())
+}
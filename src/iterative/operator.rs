@@ -0,0 +1,25 @@
+use super::{LinearOperator, InVector};
+use matrix::{Matrix, ShapeMismatch};
+use sparse::{CsrMatrix, CscMatrix};
+use vector::Vector;
+
+use std::ops::Mul;
+
+use num::traits::Zero;
+
+macro_rules! impl_operator {
+    ($($Mat:ident),*) => {
+        $(impl<T> LinearOperator<T> for $Mat<T>
+            where T: InVector + Clone + Zero + Mul<Output = T> {
+            fn dim(&self) -> (usize, usize) {
+                $Mat::dim(self)
+            }
+
+            fn apply(&self, x: &Vector<T>) -> Result<Vector<T>, ShapeMismatch> {
+                self * x
+            }
+        })*
+    };
+}
+
+impl_operator!(Matrix, CsrMatrix, CscMatrix);
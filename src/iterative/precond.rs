@@ -0,0 +1,118 @@
+use super::{Preconditioner, InVector};
+use matrix::MatrixError;
+use sparse::CsrMatrix;
+use vector::Vector;
+
+use std::prelude::v1::*;
+
+use num::traits::*;
+
+/// the Jacobi preconditioner, `M = diag(A)`
+#[derive(Clone, PartialEq, Debug)]
+pub struct Jacobi<T: InVector> {
+    inv_diag: Vector<T>
+}
+
+/// the incomplete LU factorization with no fill-in, `L` and `U` keep the sparsity pattern of `A`
+#[derive(Clone, PartialEq, Debug)]
+pub struct Ilu0<T: InVector> {
+    lu: CsrMatrix<T>,
+    diag: Vec<usize>
+}
+
+impl<T: InVector> Jacobi<T>
+    where T: Float {
+    /// creates the preconditioner from the diagonal of the system's matrix, see `Matrix::diagonal`
+    pub fn new(diagonal: &Vector<T>) -> Result<Self, MatrixError> {
+        if diagonal.iter().any(|d| d.is_zero()) {
+            return Err(MatrixError::Singular)
+        }
+
+        Ok(Jacobi { inv_diag: diagonal.map_ref(|d| d.recip()) })
+    }
+}
+
+impl<T: InVector> Preconditioner<T> for Jacobi<T>
+    where T: Float {
+    fn apply(&self, r: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.inv_diag.dim(), r.dim());
+        Vector(self.inv_diag.iter().zip(r.iter()).map(|(&d, &r)| d * r).collect())
+    }
+}
+
+impl<T: InVector> Ilu0<T>
+    where T: Float {
+    /// factorizes the matrix, fails if it is not square or a pivot is missing or zero
+    pub fn new(a: &CsrMatrix<T>) -> Result<Self, MatrixError> {
+        let (rows, cols) = a.dim();
+
+        if rows != cols {
+            return Err(MatrixError::NotSquare { rows, cols })
+        }
+
+        let (ptr, idx) = (a.row_ptr(), a.col_indices());
+        let mut values = a.values().to_vec();
+        let mut diag = Vec::with_capacity(rows);
+
+        for i in 0..rows {
+            match idx[ptr[i]..ptr[i + 1]].binary_search(&i) {
+                Ok(k) => diag.push(ptr[i] + k),
+                Err(_) => return Err(MatrixError::Singular)
+            }
+        }
+
+        for i in 0..rows {
+            for kk in ptr[i]..diag[i] {
+                let k = idx[kk];
+                let factor = values[kk] / values[diag[k]];
+                values[kk] = factor;
+
+                // only updates entries already in row i, so nothing is filled in
+                let mut p = kk + 1;
+                for q in diag[k] + 1..ptr[k + 1] {
+                    while p < ptr[i + 1] && idx[p] < idx[q] {
+                        p += 1;
+                    }
+
+                    if p < ptr[i + 1] && idx[p] == idx[q] {
+                        values[p] = values[p] - factor * values[q];
+                    }
+                }
+            }
+
+            if values[diag[i]].is_zero() {
+                return Err(MatrixError::Singular)
+            }
+        }
+
+        Ok(Ilu0 { lu: CsrMatrix::from_parts(rows, cols, ptr.to_vec(), idx.to_vec(), values), diag })
+    }
+}
+
+impl<T: InVector> Preconditioner<T> for Ilu0<T>
+    where T: Float {
+    fn apply(&self, r: &Vector<T>) -> Vector<T> {
+        let (ptr, idx, values) = (self.lu.row_ptr(), self.lu.col_indices(), self.lu.values());
+        let n = self.diag.len();
+        assert_eq!(n, r.dim());
+
+        let mut z = r.clone();
+
+        // forward substitution with the unit lower triangle
+        for i in 0..n {
+            for k in ptr[i]..self.diag[i] {
+                z[i] = z[i] - values[k] * z[idx[k]];
+            }
+        }
+
+        // back substitution with the upper triangle
+        for i in (0..n).rev() {
+            for k in self.diag[i] + 1..ptr[i + 1] {
+                z[i] = z[i] - values[k] * z[idx[k]];
+            }
+            z[i] = z[i] / values[self.diag[i]];
+        }
+
+        z
+    }
+}
@@ -0,0 +1,90 @@
+use super::*;
+use super::cg::{cg, cg_with};
+use super::bicgstab::{bicgstab, bicgstab_with};
+use super::gmres::{gmres, gmres_with};
+use super::precond::{Jacobi, Ilu0};
+use matrix::Matrix;
+use vector::Vector;
+use sparse::{CooMatrix, CsrMatrix};
+
+type VectorD = ::VectorF64;
+
+// a tridiagonal matrix with `lower` and `upper` beside a slowly growing diagonal starting at `diag`
+fn tridiagonal(n: usize, lower: f64, diag: f64, upper: f64) -> CsrMatrix<f64> {
+    let mut coo = CooMatrix::new(n, n);
+
+    for i in 0..n {
+        if i > 0 {
+            coo.push(i, i - 1, lower);
+        }
+        coo.push(i, i, diag + i as f64 * 0.1);
+        if i + 1 < n {
+            coo.push(i, i + 1, upper);
+        }
+    }
+
+    coo.to_csr()
+}
+
+fn rhs(n: usize) -> VectorD {
+    Vector((0..n).map(|i| (i as f64).sin() + 1.0).collect())
+}
+
+fn assert_solves<A: LinearOperator<f64>>(a: &A, b: &VectorD, solution: &Solution<f64>) {
+    let ax = a.apply(&solution.x).unwrap();
+    let residual = (b - &ax).mag() / b.mag();
+
+    assert!(residual < 1e-6, "residual {} is too large", residual);
+    assert!((residual - solution.residual).abs() < 1e-6)
+}
+
+#[test]
+fn conjugate_gradient() {
+    let a = tridiagonal(50, -1.0, 2.0, -1.0);
+    let b = rhs(50);
+    let options = SolverOptions::new(1e-10, 200);
+
+    let plain = cg(&a, &b, options).unwrap();
+    assert_solves(&a, &b, &plain);
+
+    let jacobi = Jacobi::new(&a.diagonal()).unwrap();
+    let preconditioned = cg_with(&a, &b, &jacobi, options).unwrap();
+    assert_solves(&a, &b, &preconditioned);
+
+    // ILU(0) of a tridiagonal matrix is its exact LU factorization
+    let ilu = Ilu0::new(&a).unwrap();
+    assert_eq!(cg_with(&a, &b, &ilu, options).unwrap().iterations, 1);
+
+    let dense = a.to_dense();
+    assert_solves(&dense, &b, &cg(&dense, &b, options).unwrap())
+}
+
+#[test]
+fn nonsymmetric() {
+    let a = tridiagonal(40, -1.5, 4.0, -0.5);
+    let b = rhs(40);
+    let options = SolverOptions::default();
+    let ilu = Ilu0::new(&a).unwrap();
+
+    assert_solves(&a, &b, &bicgstab(&a, &b, options).unwrap());
+    assert_solves(&a, &b, &bicgstab_with(&a, &b, &ilu, options).unwrap());
+    assert_solves(&a, &b, &gmres(&a, &b, 10, options).unwrap());
+    assert_solves(&a, &b, &gmres_with(&a, &b, &ilu, 10, options).unwrap());
+    assert_solves(&a.to_csc(), &b, &gmres(&a.to_csc(), &b, 50, options).unwrap())
+}
+
+#[test]
+fn solver_errors() {
+    let a = tridiagonal(10, -1.0, 2.0, -1.0);
+    let b = rhs(10);
+
+    assert_eq!(cg(&a, &b, SolverOptions::new(1e-12, 2)), Err(MatrixError::NoConvergence { iterations: 2 }));
+    assert_eq!(gmres(&a, &rhs(9), 5, SolverOptions::default()).map(|s| s.iterations),
+               Err(MatrixError::Shape(ShapeMismatch { left: (10, 10), right: (9, 1) })));
+    assert_eq!(bicgstab(&Matrix::new(2, 3), &rhs(3), SolverOptions::default()),
+               Err(MatrixError::NotSquare { rows: 2, cols: 3 }));
+    assert_eq!(Jacobi::new(&VectorD::from(vec![1.0, 0.0])), Err(MatrixError::Singular));
+
+    let zero = cg(&a, &VectorD::new(10), SolverOptions::default()).unwrap();
+    assert_eq!((zero.iterations, zero.x), (0, VectorD::new(10)))
+}
@@ -10,11 +10,28 @@ extern crate serde_derive;
 extern crate rand;
 extern crate num;
 extern crate serde;
+#[cfg(feature = "parallel")]
+extern crate rayon;
+#[cfg(feature = "npz")]
+extern crate zip;
+#[cfg(test)]
+extern crate serde_test;
 
 #[cfg(not(feature = "no_std"))]
 pub mod vector;
+#[cfg(not(feature = "no_std"))]
+pub mod matrix;
+#[cfg(not(feature = "no_std"))]
+pub mod sparse;
+#[cfg(not(feature = "no_std"))]
+pub mod iterative;
+#[cfg(not(feature = "no_std"))]
+pub mod io;
 #[cfg(any(feature = "sized", feature = "no_std"))]
 pub mod vector_sized;
+pub mod coords;
+pub mod error;
+pub mod summation;
 
 /// Marker trait to for anything that can be put in a vector
 pub auto trait InVector {}
@@ -0,0 +1,86 @@
+use super::{Matrix, MatrixError, ShapeMismatch, InVector};
+use vector::Vector;
+
+use num::traits::*;
+
+/// the Cholesky factorization of a symmetric positive definite matrix, `A = L * L^T`
+#[derive(Clone, PartialEq, Debug)]
+pub struct Cholesky<T: InVector> {
+    l: Matrix<T>
+}
+
+impl<T: InVector> Matrix<T>
+    where T: Float {
+    /// factorizes the matrix into `A = L * L^T`, only the lower triangle of the matrix is read
+    pub fn cholesky(&self) -> Result<Cholesky<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows(), cols: self.cols() })
+        }
+
+        let n = self.rows();
+        let mut l = Matrix::new(n, n);
+
+        for j in 0..n {
+            let d = (0..j).fold(self[(j, j)], |acc, k| acc - l[(j, k)] * l[(j, k)]);
+
+            // written so that NaN is rejected too
+            if !(d > T::zero()) {
+                return Err(MatrixError::NotPositiveDefinite)
+            }
+
+            let d = d.sqrt();
+            l[(j, j)] = d;
+
+            for i in j + 1..n {
+                let s = (0..j).fold(self[(i, j)], |acc, k| acc - l[(i, k)] * l[(j, k)]);
+                l[(i, j)] = s / d;
+            }
+        }
+
+        Ok(Cholesky { l })
+    }
+}
+
+impl<T: InVector> Cholesky<T>
+    where T: Float {
+    /// the lower triangular factor
+    pub fn l(&self) -> Matrix<T> {
+        self.l.clone()
+    }
+
+    /// solves `A * x = b` for `x`
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        let n = self.l.rows();
+
+        if b.dim() != n {
+            return Err(ShapeMismatch { left: self.l.dim(), right: (b.dim(), 1) }.into())
+        }
+
+        let mut x = b.clone();
+
+        // forward substitution with L
+        for i in 0..n {
+            for j in 0..i {
+                x[i] = x[i] - self.l[(i, j)] * x[j];
+            }
+            x[i] = x[i] / self.l[(i, i)];
+        }
+
+        // back substitution with L^T
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] = x[i] - self.l[(j, i)] * x[j];
+            }
+            x[i] = x[i] / self.l[(i, i)];
+        }
+
+        Ok(x)
+    }
+
+    /// the natural log of the determinant of the factorized matrix
+    pub fn log_det(&self) -> T {
+        let sum = (0..self.l.rows()).fold(T::zero(), |acc, i| acc + self.l[(i, i)].ln());
+
+        sum + sum
+    }
+}
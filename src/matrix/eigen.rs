@@ -0,0 +1,108 @@
+use super::{Matrix, MatrixError, InVector};
+use vector::Vector;
+
+use std::prelude::v1::*;
+use std::cmp::Ordering;
+
+use num::traits::*;
+
+/// the default number of Jacobi sweeps before giving up
+pub const DEFAULT_MAX_SWEEPS: usize = 100;
+
+/// the eigen decomposition of a symmetric matrix, `A = V * diag(values) * V^T`
+#[derive(Clone, PartialEq, Debug)]
+pub struct SymmetricEigen<T: InVector> {
+    /// the eigenvalues in ascending order
+    pub values: Vector<T>,
+    /// the eigenvectors as columns, in the same order as `values`
+    pub vectors: Matrix<T>
+}
+
+impl<T: InVector> Matrix<T>
+    where T: Float {
+    /// finds the eigenvalues and eigenvectors of the symmetric part of the matrix,
+    /// converging to machine precision, the rounding error of a rotation grows with the
+    /// size of the matrix so the tolerance is `n * epsilon`
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T>, MatrixError> {
+        let n = T::from(self.rows().max(1)).unwrap();
+        self.symmetric_eigen_with(n * T::epsilon(), DEFAULT_MAX_SWEEPS)
+    }
+
+    /// finds the eigenvalues and eigenvectors of the symmetric part of the matrix using cyclic Jacobi rotations,
+    /// stopping once the off diagonal norm is at most `tol` times the frobenius norm
+    pub fn symmetric_eigen_with(&self, tol: T, max_sweeps: usize) -> Result<SymmetricEigen<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows(), cols: self.cols() })
+        }
+
+        let n = self.rows();
+        let half = (T::one() + T::one()).recip();
+        let mut a = (self + &self.transpose()) * half;
+        let mut v = Matrix::identity(n);
+
+        let norm = a.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+        let off_norm = |a: &Matrix<T>| {
+            let mut sum = T::zero();
+            for p in 0..n {
+                for q in 0..n {
+                    if p != q {
+                        sum = sum + a[(p, q)] * a[(p, q)];
+                    }
+                }
+            }
+            sum.sqrt()
+        };
+
+        let mut converged = false;
+
+        for _ in 0..max_sweeps {
+            if off_norm(&a) <= tol * norm {
+                converged = true;
+                break
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[(p, q)].is_zero() {
+                        continue
+                    }
+
+                    let theta = (a[(q, q)] - a[(p, p)]) / (a[(p, q)] + a[(p, q)]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let c = (t * t + T::one()).sqrt().recip();
+                    let s = t * c;
+
+                    for k in 0..n {
+                        let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                        a[(k, p)] = c * akp - s * akq;
+                        a[(k, q)] = s * akp + c * akq;
+                    }
+
+                    for k in 0..n {
+                        let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                        a[(p, k)] = c * apk - s * aqk;
+                        a[(q, k)] = s * apk + c * aqk;
+                    }
+
+                    for k in 0..n {
+                        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = c * vkp - s * vkq;
+                        v[(k, q)] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        if !converged && off_norm(&a) > tol * norm {
+            return Err(MatrixError::NoConvergence { iterations: max_sweeps })
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[(i, i)].partial_cmp(&a[(j, j)]).unwrap_or(Ordering::Equal));
+
+        let values = Vector(order.iter().map(|&i| a[(i, i)]).collect());
+        let vectors = Matrix::from_cols(order.iter().map(|&i| v.col_vector(i)).collect());
+
+        Ok(SymmetricEigen { values, vectors })
+    }
+}
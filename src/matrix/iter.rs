@@ -0,0 +1,122 @@
+use super::{Matrix, InVector};
+
+use std::iter::IntoIterator;
+use std::vec;
+use std::slice;
+use std::mem;
+
+/// iterates over the rows of a matrix as slices, yielding empty rows when the matrix has no columns
+#[derive(Clone, Debug)]
+pub struct Rows<'a, T: 'a> {
+    data: &'a [T],
+    cols: usize,
+    rows: usize
+}
+
+/// iterates mutably over the rows of a matrix as slices
+#[derive(Debug)]
+pub struct RowsMut<'a, T: 'a> {
+    data: &'a mut [T],
+    cols: usize,
+    rows: usize
+}
+
+impl<'a, T> Iterator for Rows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            return None
+        }
+
+        let (row, rest) = self.data.split_at(self.cols);
+        self.data = rest;
+        self.rows -= 1;
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rows, Some(self.rows))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Rows<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            return None
+        }
+
+        let (rest, row) = self.data.split_at(self.data.len() - self.cols);
+        self.data = rest;
+        self.rows -= 1;
+        Some(row)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Rows<'a, T> {}
+
+impl<'a, T> Iterator for RowsMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            return None
+        }
+
+        let (row, rest) = mem::replace(&mut self.data, &mut []).split_at_mut(self.cols);
+        self.data = rest;
+        self.rows -= 1;
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rows, Some(self.rows))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RowsMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            return None
+        }
+
+        let data = mem::replace(&mut self.data, &mut []);
+        let split = data.len() - self.cols;
+        let (rest, row) = data.split_at_mut(split);
+        self.data = rest;
+        self.rows -= 1;
+        Some(row)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RowsMut<'a, T> {}
+
+impl<T: InVector> IntoIterator for Matrix<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<T: InVector> Matrix<T> {
+    /// iterates over the elements in row-major order
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// iterates mutably over the elements in row-major order
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// iterates over the rows of the matrix as slices
+    pub fn iter_rows(&self) -> Rows<'_, T> {
+        Rows { data: &self.data, cols: self.cols, rows: self.rows }
+    }
+
+    /// iterates mutably over the rows of the matrix as slices
+    pub fn iter_rows_mut(&mut self) -> RowsMut<'_, T> {
+        RowsMut { data: &mut self.data, cols: self.cols, rows: self.rows }
+    }
+}
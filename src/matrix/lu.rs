@@ -0,0 +1,181 @@
+use super::{Matrix, MatrixError, ShapeMismatch, InVector};
+use vector::Vector;
+
+use std::prelude::v1::*;
+use std::cmp::Ordering;
+
+use num::traits::*;
+
+/// the LU factorization of a square matrix with partial pivoting, `P * A = L * U`
+#[derive(Clone, PartialEq, Debug)]
+pub struct LU<T: InVector> {
+    // L (without its unit diagonal) and U packed into one matrix
+    lu: Matrix<T>,
+    perm: Vec<usize>,
+    swaps: usize
+}
+
+impl<T: InVector> Matrix<T>
+    where T: Float {
+    /// factorizes the matrix into `P * A = L * U` using partial pivoting
+    pub fn lu(&self) -> Result<LU<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare { rows: self.rows(), cols: self.cols() })
+        }
+
+        let n = self.rows();
+        let mut lu = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+
+        for k in 0..n {
+            let pivot_row = (k..n).max_by(|&a, &b| {
+                lu[(a, k)].abs().partial_cmp(&lu[(b, k)].abs()).unwrap_or(Ordering::Equal)
+            }).unwrap();
+
+            if pivot_row != k {
+                lu.swap_rows(pivot_row, k);
+                perm.swap(pivot_row, k);
+                swaps += 1;
+            }
+
+            let pivot = lu[(k, k)];
+
+            if pivot.is_zero() {
+                continue
+            }
+
+            for i in k + 1..n {
+                let factor = lu[(i, k)] / pivot;
+                lu[(i, k)] = factor;
+
+                for j in k + 1..n {
+                    lu[(i, j)] = lu[(i, j)] - factor * lu[(k, j)];
+                }
+            }
+        }
+
+        Ok(LU { lu, perm, swaps })
+    }
+
+    /// solves `A * x = b` for `x`
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        self.lu()?.solve(b)
+    }
+
+    /// the determinant of the matrix
+    pub fn det(&self) -> Result<T, MatrixError> {
+        Ok(self.lu()?.det())
+    }
+
+    /// the inverse of the matrix
+    pub fn inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        self.lu()?.inverse()
+    }
+}
+
+impl<T: InVector> LU<T>
+    where T: Float {
+    /// the unit lower triangular factor
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.lu.rows();
+        let mut l = Matrix::identity(n);
+
+        for i in 0..n {
+            for j in 0..i {
+                l[(i, j)] = self.lu[(i, j)];
+            }
+        }
+
+        l
+    }
+
+    /// the upper triangular factor
+    pub fn u(&self) -> Matrix<T> {
+        let n = self.lu.rows();
+        let mut u = Matrix::new(n, n);
+
+        for i in 0..n {
+            for j in i..n {
+                u[(i, j)] = self.lu[(i, j)];
+            }
+        }
+
+        u
+    }
+
+    /// the permutation matrix
+    pub fn p(&self) -> Matrix<T> {
+        let n = self.lu.rows();
+        let mut p = Matrix::new(n, n);
+
+        for (i, &j) in self.perm.iter().enumerate() {
+            p[(i, j)] = T::one();
+        }
+
+        p
+    }
+
+    /// the permutation as row indices, row `i` of `P * A` is row `permutation()[i]` of `A`
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// checks if any of the pivots are zero
+    pub fn is_singular(&self) -> bool {
+        (0..self.lu.rows()).any(|i| self.lu[(i, i)].is_zero())
+    }
+
+    /// the determinant of the factorized matrix
+    pub fn det(&self) -> T {
+        let det = (0..self.lu.rows()).fold(T::one(), |acc, i| acc * self.lu[(i, i)]);
+
+        if self.swaps % 2 == 0 { det } else { -det }
+    }
+
+    /// solves `A * x = b` for `x`
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        let n = self.lu.rows();
+
+        if b.dim() != n {
+            return Err(ShapeMismatch { left: self.lu.dim(), right: (b.dim(), 1) }.into())
+        }
+
+        if self.is_singular() {
+            return Err(MatrixError::Singular)
+        }
+
+        let mut x: Vector<T> = Vector(self.perm.iter().map(|&i| b[i]).collect());
+
+        // forward substitution with the unit lower triangle
+        for i in 0..n {
+            for j in 0..i {
+                x[i] = x[i] - self.lu[(i, j)] * x[j];
+            }
+        }
+
+        // back substitution with the upper triangle
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] = x[i] - self.lu[(i, j)] * x[j];
+            }
+            x[i] = x[i] / self.lu[(i, i)];
+        }
+
+        Ok(x)
+    }
+
+    /// the inverse of the factorized matrix
+    pub fn inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        let n = self.lu.rows();
+        let mut cols = Vec::with_capacity(n);
+
+        for j in 0..n {
+            let mut e = Vector::new(n);
+            e[j] = T::one();
+            cols.push(self.solve(&e)?);
+        }
+
+        Ok(Matrix::from_cols(cols))
+    }
+}
@@ -0,0 +1,263 @@
+use super::{Matrix, InVector};
+use vector::Vector;
+use vector::view::{VectorView, VectorViewMut};
+
+use std::{
+    vec::Vec,
+    ops::{Index, IndexMut, Mul},
+    convert::Into
+};
+
+use num::traits::*;
+
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+
+impl<T: InVector> Matrix<T> {
+    /// creates a `rows` by `cols` matrix of 0.0s
+    pub fn new(rows: usize, cols: usize) -> Self
+    where T: Zero {
+        Matrix {
+            rows, cols,
+            data: (0..rows * cols).map(|_| T::zero()).collect()
+        }
+    }
+
+    /// creates the `dim` by `dim` identity matrix
+    pub fn identity(dim: usize) -> Self
+    where T: Zero + One {
+        let mut mat = Self::new(dim, dim);
+
+        for i in 0..dim {
+            mat[(i, i)] = T::one();
+        }
+
+        mat
+    }
+
+    /// creates a matrix from row-major data, panics if `data.len() != rows * cols`
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(rows * cols, data.len(), "data does not fit a {}x{} matrix", rows, cols);
+        Matrix { rows, cols, data }
+    }
+
+    /// creates a matrix whose rows are the given vectors, panics if the rows have different dimensions
+    pub fn from_rows(rows: Vec<Vector<T>>) -> Self {
+        let cols = rows.first().map(|r| r.dim()).unwrap_or(0);
+        let count = rows.len();
+        let mut data = Vec::with_capacity(count * cols);
+
+        for row in rows {
+            assert_eq!(cols, row.dim(), "rows of a matrix must have the same dimension");
+            data.extend(row.into_iter());
+        }
+
+        Matrix { rows: count, cols, data }
+    }
+
+    /// creates a matrix whose columns are the given vectors, panics if the columns have different dimensions
+    pub fn from_cols(cols: Vec<Vector<T>>) -> Self
+    where T: Clone {
+        Self::from_rows(cols).transpose()
+    }
+
+    /// the number of rows in the matrix
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// the number of columns in the matrix
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// the shape of the matrix as `(rows, cols)`
+    pub fn dim(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// checks if the matrix has as many rows as columns
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// gets value at `(row, col)`, and clones it. This is unnecessary if `T` is `Copy`.
+    pub fn get(&self, row: usize, col: usize) -> T
+    where T: Clone {
+        self[(row, col)].clone()
+    }
+
+    /// borrows a row of the matrix as a slice
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "row index out of bounds");
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// mutably borrows a row of the matrix as a slice
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(row < self.rows, "row index out of bounds");
+        &mut self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// copies a row of the matrix into a vector
+    pub fn row_vector(&self, row: usize) -> Vector<T>
+    where T: Clone {
+        Vector::from(self.row(row))
+    }
+
+    /// copies a column of the matrix into a vector
+    pub fn col_vector(&self, col: usize) -> Vector<T>
+    where T: Clone {
+        assert!(col < self.cols, "column index out of bounds");
+        Vector((0..self.rows).map(|r| self.data[r * self.cols + col].clone()).collect())
+    }
+
+    /// copies the main diagonal of the matrix into a vector
+    pub fn diagonal(&self) -> Vector<T>
+    where T: Clone {
+        Vector((0..self.rows.min(self.cols)).map(|i| self[(i, i)].clone()).collect())
+    }
+
+    /// borrows a row of the matrix as a vector view
+    pub fn row_view(&self, row: usize) -> VectorView<'_, T> {
+        VectorView::strided_from(self.row(row), 0, 1, self.cols)
+    }
+
+    /// borrows a column of the matrix as a strided vector view
+    pub fn col_view(&self, col: usize) -> VectorView<'_, T> {
+        assert!(col < self.cols, "column index out of bounds");
+        VectorView::strided_from(&self.data, col, self.cols, self.rows)
+    }
+
+    /// mutably borrows a column of the matrix as a strided vector view
+    pub fn col_view_mut(&mut self, col: usize) -> VectorViewMut<'_, T> {
+        assert!(col < self.cols, "column index out of bounds");
+        VectorViewMut::strided_from(&mut self.data, col, self.cols, self.rows)
+    }
+
+    /// swaps two rows of the matrix
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            for c in 0..self.cols {
+                self.data.swap(a * self.cols + c, b * self.cols + c);
+            }
+        }
+    }
+
+    /// gets the transpose of the matrix
+    pub fn transpose(&self) -> Self
+    where T: Clone {
+        let mut data = Vec::with_capacity(self.data.len());
+
+        for c in 0..self.cols {
+            for r in 0..self.rows {
+                data.push(self[(r, c)].clone());
+            }
+        }
+
+        Matrix { rows: self.cols, cols: self.rows, data }
+    }
+
+    /// the element-wise product of two matrices of the same shape
+    pub fn hadamard<U, O>(&self, other: &Matrix<U>) -> Matrix<O>
+        where U: InVector + Clone,
+              O: InVector,
+              T: Clone + Mul<U, Output = O> {
+        assert_eq!(self.dim(), other.dim());
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter()
+                           .zip(other.data.iter())
+                           .map(|(i, j)| i.clone() * j.clone())
+                           .collect()
+        }
+    }
+
+    /// gets the elements of the matrix in row-major order
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// mutably gets the elements of the matrix in row-major order
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// consumes the matrix, returning the elements in row-major order
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// conversion functions between different matrix types (if the type implements from)
+    pub fn into<U>(self) -> Matrix<U>
+        where U: InVector,
+              T: Into<U> {
+        self.map(|x| x.into())
+    }
+
+    /// maps the matrix's component's according to the function provided
+    pub fn map<U: InVector, F>(self, f: F) -> Matrix<U>
+        where F: Fn(T) -> U {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.into_iter().map(f).collect()
+        }
+    }
+
+    /// maps the matrix's component's according to the function provided
+    pub fn map_ref<U: InVector, F>(&self, f: F) -> Matrix<U>
+        where F: Fn(&T) -> U {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(f).collect()
+        }
+    }
+}
+
+// traits
+impl<T: InVector> From<Vector<T>> for Matrix<T> {
+    // get a column matrix from a vector
+    fn from(value: Vector<T>) -> Self {
+        Matrix { rows: value.dim(), cols: 1, data: value.0 }
+    }
+}
+
+impl<T: InVector> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(row < self.rows && col < self.cols, "matrix index out of bounds");
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T: InVector> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(row < self.rows && col < self.cols, "matrix index out of bounds");
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+impl<'de, T: InVector + Deserialize<'de>> Deserialize<'de> for Matrix<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        #[serde(rename = "Matrix")]
+        struct Parts<T> {
+            rows: usize,
+            cols: usize,
+            data: Vec<T>
+        }
+
+        let Parts { rows, cols, data } = Parts::deserialize(deserializer)?;
+
+        if rows.checked_mul(cols) != Some(data.len()) {
+            return Err(D::Error::custom(format_args!("data does not fit a {}x{} matrix", rows, cols)))
+        }
+
+        Ok(Matrix { rows, cols, data })
+    }
+}
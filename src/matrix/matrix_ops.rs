@@ -0,0 +1,302 @@
+use std::prelude::v1::*;
+use std::ops::*;
+
+use super::{Matrix, ShapeMismatch, InVector};
+use vector::Vector;
+
+use num::traits::Zero;
+
+macro_rules! impl_op {
+    (own => $Op:ident, $func:ident, $op:tt) => {
+        impl<T, U: Clone, O> $Op<U> for Matrix<T>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector {
+            type Output = Matrix<O>;
+
+            default fn $func(self, rhs: U) -> Self::Output {
+                self.map(|i| i.$func(rhs.clone()))
+            }
+        }
+    };
+    (borrow => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, T: Clone, U: Clone, O> $Op<U> for &'a Matrix<T>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector {
+            type Output = Matrix<O>;
+
+            default fn $func(self, rhs: U) -> Self::Output {
+                self.map_ref(|i| i.clone().$func(rhs.clone()))
+            }
+        }
+    };
+    (own, own => $Op:ident, $func:ident, $op:tt) => {
+        impl<T, U, O> $Op<Matrix<U>> for Matrix<T>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector {
+            type Output = Matrix<O>;
+
+            default fn $func(self, rhs: Matrix<U>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                let (rows, cols) = self.dim();
+                Matrix::from_vec(rows, cols, self.into_iter()
+                                                 .zip(rhs.into_iter())
+                                                 .map(|(i, j)| i.$func(j))
+                                                 .collect())
+            }
+        }
+    };
+    (own, borrow => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, T, U: Clone, O> $Op<&'a Matrix<U>> for Matrix<T>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector {
+            type Output = Matrix<O>;
+
+            default fn $func(self, rhs: &'a Matrix<U>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                let (rows, cols) = self.dim();
+                Matrix::from_vec(rows, cols, self.into_iter()
+                                                 .zip(rhs.iter())
+                                                 .map(|(i, j)| i.$func(j.clone()))
+                                                 .collect())
+            }
+        }
+    };
+    (borrow, own => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, T: Clone, U, O> $Op<Matrix<U>> for &'a Matrix<T>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector {
+            type Output = Matrix<O>;
+
+            default fn $func(self, rhs: Matrix<U>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                let (rows, cols) = self.dim();
+                Matrix::from_vec(rows, cols, self.iter()
+                                                 .zip(rhs.into_iter())
+                                                 .map(|(i, j)| i.clone().$func(j))
+                                                 .collect())
+            }
+        }
+    };
+    (borrow, borrow => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, T: Clone, U: Clone, O> $Op<&'a Matrix<U>> for &'a Matrix<T>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector {
+            type Output = Matrix<O>;
+
+            default fn $func(self, rhs: &'a Matrix<U>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                let (rows, cols) = self.dim();
+                Matrix::from_vec(rows, cols, self.iter()
+                                                 .zip(rhs.iter())
+                                                 .map(|(i, j)| i.clone().$func(j.clone()))
+                                                 .collect())
+            }
+        }
+    };
+    (assign, own, own => $Op:ident, $func:ident, $op:tt) => {
+        impl<T, U> $Op<Matrix<U>> for Matrix<T>
+            where T: InVector + $Op<U>,
+                  U: InVector {
+
+            default fn $func(&mut self, rhs: Matrix<U>) {
+                assert_eq!(self.dim(), rhs.dim());
+                self.iter_mut()
+                    .zip(rhs.into_iter())
+                    .for_each(|(i, j)| i.$func(j))
+            }
+        }
+    };
+    (assign, own, borrow => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, T, U: Clone> $Op<&'a Matrix<U>> for Matrix<T>
+            where T: InVector + $Op<U>,
+                  U: InVector {
+
+            default fn $func(&mut self, rhs: &'a Matrix<U>) {
+                assert_eq!(self.dim(), rhs.dim());
+                self.iter_mut()
+                    .zip(rhs.iter())
+                    .for_each(|(i, j)| i.$func(j.clone()))
+            }
+        }
+    };
+    (assign, borrow, own => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, T, U> $Op<Matrix<U>> for &'a mut Matrix<T>
+            where T: InVector + $Op<U>,
+                  U: InVector {
+
+            default fn $func(&mut self, rhs: Matrix<U>) {
+                assert_eq!(self.dim(), rhs.dim());
+                self.iter_mut()
+                    .zip(rhs.into_iter())
+                    .for_each(|(i, j)| i.$func(j))
+            }
+        }
+    };
+    (assign, borrow, borrow => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, T, U: Clone> $Op<&'a Matrix<U>> for &'a mut Matrix<T>
+            where T: InVector + $Op<U>,
+                  U: InVector {
+
+            default fn $func(&mut self, rhs: &'a Matrix<U>) {
+                assert_eq!(self.dim(), rhs.dim());
+                self.iter_mut()
+                    .zip(rhs.iter())
+                    .for_each(|(i, j)| i.$func(j.clone()))
+            }
+        }
+    };
+    (op => $Op:ident, $func:ident, $op:tt => $self_type:tt) => {
+        impl_op!($self_type => $Op, $func, $op);
+    };
+    (op all => $self_type:tt, $other_type:tt) => {
+        impl_op!(op => Add, add, + => $self_type, $other_type);
+        impl_op!(op => Sub, sub, - => $self_type, $other_type);
+        impl_op!(op => Div, div, / => $self_type, $other_type);
+        impl_op!(op => Rem, rem, % => $self_type, $other_type);
+
+        impl_op!(op => BitAnd, bitand, & => $self_type, $other_type);
+        impl_op!(op => BitOr, bitor, | => $self_type, $other_type);
+        impl_op!(op => BitXor, bitxor, ^ => $self_type, $other_type);
+        impl_op!(op => Shl, shl, << => $self_type, $other_type);
+        impl_op!(op => Shr, shr, >> => $self_type, $other_type);
+    };
+    (op assign all => $self_type:tt, $other_type:tt) => {
+        impl_op!(op assign => AddAssign, add_assign, += => $self_type, $other_type);
+        impl_op!(op assign => SubAssign, sub_assign, -= => $self_type, $other_type);
+        impl_op!(op assign => DivAssign, div_assign, /= => $self_type, $other_type);
+        impl_op!(op assign => RemAssign, rem_assign, %= => $self_type, $other_type);
+
+        impl_op!(op assign => BitAndAssign, bitand_assign, &= => $self_type, $other_type);
+        impl_op!(op assign => BitOrAssign, bitor_assign, |= => $self_type, $other_type);
+        impl_op!(op assign => BitXorAssign, bitxor_assign, ^= => $self_type, $other_type);
+        impl_op!(op assign => ShlAssign, shl_assign, <<= => $self_type, $other_type);
+        impl_op!(op assign => ShrAssign, shr_assign, >>= => $self_type, $other_type);
+    };
+    (op => $Op:ident, $func:ident, $op:tt => $self_type:tt, $other_type:tt) => {
+        impl_op!($self_type, $other_type => $Op, $func, $op);
+    };
+    (op assign => $Op:ident, $func:ident, $op:tt => $self_type:tt, $other_type:tt) => {
+        impl_op!(assign, $self_type, $other_type => $Op, $func, $op);
+    };
+}
+
+// `Mul` between two matrices is the matrix product, use `hadamard` for the element-wise product
+impl_op!(op all => own, own);
+impl_op!(op all => own, borrow);
+impl_op!(op all => borrow, own);
+impl_op!(op all => borrow, borrow);
+
+impl_op!(op assign all => own, own);
+impl_op!(op assign all => own, borrow);
+impl_op!(op assign all => borrow, own);
+impl_op!(op assign all => borrow, borrow);
+
+impl_op!(op => Mul, mul, * => own);
+impl_op!(op => Mul, mul, * => borrow);
+
+impl_op!(op => Div, div, / => own);
+impl_op!(op => Div, div, / => borrow);
+
+impl<T: InVector, O: InVector> Neg for Matrix<T>
+where T: Neg<Output = O> {
+    type Output = Matrix<O>;
+
+    default fn neg(self) -> Self::Output {
+        self.map(|x| -x)
+    }
+}
+
+impl<'a, T: InVector + Clone, O: InVector> Neg for &'a Matrix<T>
+where T: Neg<Output = O> {
+    type Output = Matrix<O>;
+
+    fn neg(self) -> Self::Output {
+        self.map_ref(|x| -x.clone())
+    }
+}
+
+impl<T: InVector, O: InVector> Not for Matrix<T>
+where T: Not<Output = O> {
+    type Output = Matrix<O>;
+
+    default fn not(self) -> Self::Output {
+        self.map(|x| !x)
+    }
+}
+
+impl<'a, T: InVector + Clone, O: InVector> Not for &'a Matrix<T>
+where T: Not<Output = O> {
+    type Output = Matrix<O>;
+
+    fn not(self) -> Self::Output {
+        self.map_ref(|x| !x.clone())
+    }
+}
+
+fn mul_matrix_matrix<T, U, O>(lhs: &Matrix<T>, rhs: &Matrix<U>) -> Result<Matrix<O>, ShapeMismatch>
+    where T: InVector + Clone + Mul<U, Output = O>,
+          U: InVector + Clone, O: InVector + Zero {
+    if lhs.cols() != rhs.rows() {
+        return Err(ShapeMismatch { left: lhs.dim(), right: rhs.dim() })
+    }
+
+    let mut data = Vec::with_capacity(lhs.rows() * rhs.cols());
+
+    for r in 0..lhs.rows() {
+        for c in 0..rhs.cols() {
+            data.push((0..lhs.cols()).fold(O::zero(), |acc, k| {
+                acc + lhs[(r, k)].clone() * rhs[(k, c)].clone()
+            }));
+        }
+    }
+
+    Ok(Matrix::from_vec(lhs.rows(), rhs.cols(), data))
+}
+
+fn mul_matrix_vector<T, U, O>(lhs: &Matrix<T>, rhs: &Vector<U>) -> Result<Vector<O>, ShapeMismatch>
+    where T: InVector + Clone + Mul<U, Output = O>,
+          U: InVector + Clone, O: InVector + Zero {
+    if lhs.cols() != rhs.dim() {
+        return Err(ShapeMismatch { left: lhs.dim(), right: (rhs.dim(), 1) })
+    }
+
+    Ok(Vector((0..lhs.rows()).map(|r| {
+        lhs.row(r).iter().zip(rhs.iter()).fold(O::zero(), |acc, (i, j)| acc + i.clone() * j.clone())
+    }).collect()))
+}
+
+fn mul_vector_matrix<T, U, O>(lhs: &Vector<T>, rhs: &Matrix<U>) -> Result<Vector<O>, ShapeMismatch>
+    where T: InVector + Clone + Mul<U, Output = O>,
+          U: InVector + Clone, O: InVector + Zero {
+    if lhs.dim() != rhs.rows() {
+        return Err(ShapeMismatch { left: (1, lhs.dim()), right: rhs.dim() })
+    }
+
+    Ok(Vector((0..rhs.cols()).map(|c| {
+        lhs.iter().enumerate().fold(O::zero(), |acc, (k, i)| acc + i.clone() * rhs[(k, c)].clone())
+    }).collect()))
+}
+
+macro_rules! impl_mat_mul {
+    (@impl [$($lt:tt)*] $Lhs:ty, $Rhs:ty => $Out:ty, $fun:ident) => {
+        impl<$($lt)* T, U, O> Mul<$Rhs> for $Lhs
+            where T: InVector + Clone + Mul<U, Output = O>,
+                  U: InVector + Clone, O: InVector + Zero {
+            type Output = Result<$Out, ShapeMismatch>;
+
+            fn mul(self, rhs: $Rhs) -> Self::Output {
+                $fun(&self, &rhs)
+            }
+        }
+    };
+    ($Lhs:ident, $Rhs:ident => $Out:ident, $fun:ident) => {
+        impl_mat_mul!(@impl [] $Lhs<T>, $Rhs<U> => $Out<O>, $fun);
+        impl_mat_mul!(@impl ['a,] $Lhs<T>, &'a $Rhs<U> => $Out<O>, $fun);
+        impl_mat_mul!(@impl ['a,] &'a $Lhs<T>, $Rhs<U> => $Out<O>, $fun);
+        impl_mat_mul!(@impl ['a,] &'a $Lhs<T>, &'a $Rhs<U> => $Out<O>, $fun);
+    };
+}
+
+impl_mat_mul!(Matrix, Matrix => Matrix, mul_matrix_matrix);
+impl_mat_mul!(Matrix, Vector => Vector, mul_matrix_vector);
+impl_mat_mul!(Vector, Matrix => Vector, mul_vector_matrix);
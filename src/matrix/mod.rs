@@ -0,0 +1,80 @@
+pub mod matrix_impl;
+pub mod matrix_ops;
+pub mod iter;
+pub mod lu;
+pub mod qr;
+pub mod cholesky;
+pub mod eigen;
+pub mod svd;
+
+#[cfg(test)]
+mod tests;
+
+use super::InVector;
+use std::prelude::v1::*;
+use std::fmt;
+
+/// a dense, dynamically sized matrix stored in row-major order
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct Matrix<T: InVector> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>
+}
+
+impl<T> !InVector for Matrix<T> {}
+
+/// error returned when the shapes of two operands can not be multiplied,
+/// vectors are treated as a column on the right and a row on the left
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ShapeMismatch {
+    pub left: (usize, usize),
+    pub right: (usize, usize)
+}
+
+impl fmt::Display for ShapeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "can not multiply a {}x{} by a {}x{}",
+               self.left.0, self.left.1, self.right.0, self.right.1)
+    }
+}
+
+/// errors produced by the matrix factorizations and solvers
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MatrixError {
+    /// the operation needs a square matrix
+    NotSquare { rows: usize, cols: usize },
+    /// the matrix is singular, so the system has no unique solution
+    Singular,
+    /// the matrix is not symmetric positive definite
+    NotPositiveDefinite,
+    /// the system has fewer equations than unknowns
+    Underdetermined { rows: usize, cols: usize },
+    /// an iterative algorithm did not converge within its iteration limit
+    NoConvergence { iterations: usize },
+    /// the operands have incompatible shapes
+    Shape(ShapeMismatch)
+}
+
+impl From<ShapeMismatch> for MatrixError {
+    fn from(value: ShapeMismatch) -> Self {
+        MatrixError::Shape(value)
+    }
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MatrixError::NotSquare { rows, cols } => write!(f, "expected a square matrix, found a {}x{}", rows, cols),
+            MatrixError::Singular => write!(f, "matrix is singular"),
+            MatrixError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            MatrixError::Underdetermined { rows, cols } => write!(f, "a {}x{} system is underdetermined", rows, cols),
+            MatrixError::NoConvergence { iterations } => write!(f, "did not converge after {} iterations", iterations),
+            MatrixError::Shape(ref err) => write!(f, "{}", err)
+        }
+    }
+}
+
+impl ::std::error::Error for ShapeMismatch {}
+
+impl ::std::error::Error for MatrixError {}
@@ -0,0 +1,134 @@
+use super::{Matrix, MatrixError, ShapeMismatch, InVector};
+use vector::Vector;
+
+use std::prelude::v1::*;
+
+use num::traits::*;
+
+/// the QR factorization of a matrix computed with Householder reflections, `A = Q * R`
+#[derive(Clone, PartialEq, Debug)]
+pub struct QR<T: InVector> {
+    r: Matrix<T>,
+    // unit householder vectors, the `k`th one acts on rows `k..`
+    reflectors: Vec<Vector<T>>
+}
+
+impl<T: InVector> Matrix<T>
+    where T: Float {
+    /// factorizes the matrix into `A = Q * R` using Householder reflections
+    pub fn qr(&self) -> QR<T> {
+        let (rows, cols) = self.dim();
+        let mut r = self.clone();
+        let mut reflectors = Vec::with_capacity(rows.min(cols));
+
+        for k in 0..rows.min(cols) {
+            let x = Vector((k..rows).map(|i| r[(i, k)]).collect());
+            let norm = x.mag();
+            let alpha = if x[0] >= T::zero() { -norm } else { norm };
+
+            let mut v = x;
+            v[0] = v[0] - alpha;
+            let v_mag = v.mag();
+
+            let v = if v_mag.is_zero() { v } else { &v / v_mag };
+
+            for j in k..cols {
+                let col = Vector((k..rows).map(|i| r[(i, j)]).collect());
+                let s = v.dot(&col);
+                let s = s + s;
+
+                for (i, vi) in (k..rows).zip(v.iter()) {
+                    r[(i, j)] = r[(i, j)] - s * *vi;
+                }
+            }
+
+            reflectors.push(v);
+        }
+
+        QR { r, reflectors }
+    }
+
+    /// finds the `x` minimizing `|A * x - b|`, returning it together with the residual norm
+    pub fn lstsq(&self, b: &Vector<T>) -> Result<(Vector<T>, T), MatrixError> {
+        self.qr().lstsq(b)
+    }
+}
+
+impl<T: InVector> QR<T>
+    where T: Float {
+    /// the orthogonal factor
+    pub fn q(&self) -> Matrix<T> {
+        let rows = self.r.rows();
+        let mut q = Matrix::identity(rows);
+
+        for (k, v) in self.reflectors.iter().enumerate().rev() {
+            for j in 0..rows {
+                let col = Vector((k..rows).map(|i| q[(i, j)]).collect());
+                let s = v.dot(&col);
+                let s = s + s;
+
+                for (i, vi) in (k..rows).zip(v.iter()) {
+                    q[(i, j)] = q[(i, j)] - s * *vi;
+                }
+            }
+        }
+
+        q
+    }
+
+    /// the upper triangular factor
+    pub fn r(&self) -> Matrix<T> {
+        self.r.clone()
+    }
+
+    /// computes `Q^T * b` without forming `Q`
+    pub fn qt_mul(&self, b: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        let rows = self.r.rows();
+
+        if b.dim() != rows {
+            return Err(ShapeMismatch { left: (rows, rows), right: (b.dim(), 1) }.into())
+        }
+
+        let mut out = b.clone();
+
+        for (k, v) in self.reflectors.iter().enumerate() {
+            let tail = Vector(out[k..].to_vec());
+            let s = v.dot(&tail);
+            let s = s + s;
+
+            for (o, vi) in out[k..].iter_mut().zip(v.iter()) {
+                *o = *o - s * *vi;
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// finds the `x` minimizing `|A * x - b|`, returning it together with the residual norm
+    pub fn lstsq(&self, b: &Vector<T>) -> Result<(Vector<T>, T), MatrixError> {
+        let (rows, cols) = self.r.dim();
+
+        if rows < cols {
+            return Err(MatrixError::Underdetermined { rows, cols })
+        }
+
+        let c = self.qt_mul(b)?;
+
+        if (0..cols).any(|i| self.r[(i, i)].is_zero()) {
+            return Err(MatrixError::Singular)
+        }
+
+        let mut x = Vector(c[..cols].to_vec());
+
+        for i in (0..cols).rev() {
+            for j in i + 1..cols {
+                x[i] = x[i] - self.r[(i, j)] * x[j];
+            }
+            x[i] = x[i] / self.r[(i, i)];
+        }
+
+        let residual = Vector(c[cols..].to_vec()).mag();
+
+        Ok((x, residual))
+    }
+}
@@ -0,0 +1,165 @@
+use super::{Matrix, MatrixError, InVector};
+use super::eigen::DEFAULT_MAX_SWEEPS;
+use vector::Vector;
+
+use std::prelude::v1::*;
+use std::cmp::Ordering;
+
+use num::traits::*;
+
+/// the thin singular value decomposition of a matrix, `A = U * diag(singular_values) * V^T`
+#[derive(Clone, PartialEq, Debug)]
+pub struct Svd<T: InVector> {
+    /// the left singular vectors as columns, columns for zero singular values are left as zeros
+    pub u: Matrix<T>,
+    /// the singular values in descending order
+    pub singular_values: Vector<T>,
+    /// the right singular vectors as rows
+    pub v_t: Matrix<T>
+}
+
+impl<T: InVector> Matrix<T>
+    where T: Float {
+    /// finds the singular value decomposition, converging to machine precision
+    pub fn svd(&self) -> Result<Svd<T>, MatrixError> {
+        self.svd_with(T::epsilon(), DEFAULT_MAX_SWEEPS)
+    }
+
+    /// finds the singular value decomposition using one-sided Jacobi rotations,
+    /// stopping once every pair of columns is orthogonal to within `tol`
+    pub fn svd_with(&self, tol: T, max_sweeps: usize) -> Result<Svd<T>, MatrixError> {
+        if self.rows() < self.cols() {
+            let Svd { u, singular_values, v_t } = self.transpose().svd_with(tol, max_sweeps)?;
+
+            return Ok(Svd { u: v_t.transpose(), singular_values, v_t: u.transpose() })
+        }
+
+        let (rows, cols) = self.dim();
+        let mut u = self.clone();
+        let mut v = Matrix::identity(cols);
+        let mut converged = false;
+
+        for _ in 0..max_sweeps {
+            let mut rotated = false;
+
+            for p in 0..cols {
+                for q in p + 1..cols {
+                    let (up, uq) = (u.col_vector(p), u.col_vector(q));
+                    let alpha = up.magsq();
+                    let beta = uq.magsq();
+                    let gamma = up.dot(&uq);
+
+                    if gamma.is_zero() || gamma.abs() <= tol * (alpha * beta).sqrt() {
+                        continue
+                    }
+
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (gamma + gamma);
+                    let t = zeta.signum() / (zeta.abs() + (zeta * zeta + T::one()).sqrt());
+                    let c = (t * t + T::one()).sqrt().recip();
+                    let s = t * c;
+
+                    for k in 0..rows {
+                        let (ukp, ukq) = (u[(k, p)], u[(k, q)]);
+                        u[(k, p)] = c * ukp - s * ukq;
+                        u[(k, q)] = s * ukp + c * ukq;
+                    }
+
+                    for k in 0..cols {
+                        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = c * vkp - s * vkq;
+                        v[(k, q)] = s * vkp + c * vkq;
+                    }
+                }
+            }
+
+            if !rotated {
+                converged = true;
+                break
+            }
+        }
+
+        if !converged && cols > 1 {
+            return Err(MatrixError::NoConvergence { iterations: max_sweeps })
+        }
+
+        let sigma: Vec<T> = (0..cols).map(|j| u.col_vector(j).mag()).collect();
+
+        let mut order: Vec<usize> = (0..cols).collect();
+        order.sort_by(|&i, &j| sigma[j].partial_cmp(&sigma[i]).unwrap_or(Ordering::Equal));
+
+        let u = Matrix::from_cols(order.iter().map(|&j| {
+            let col = u.col_vector(j);
+            if sigma[j].is_zero() { col } else { &col / sigma[j] }
+        }).collect());
+        let v_t = Matrix::from_rows(order.iter().map(|&j| v.col_vector(j)).collect());
+        let singular_values = Vector(order.iter().map(|&j| sigma[j]).collect());
+
+        Ok(Svd { u, singular_values, v_t })
+    }
+
+    /// the number of singular values greater than `tol`
+    pub fn rank(&self, tol: T) -> Result<usize, MatrixError> {
+        Ok(self.svd()?.rank(tol))
+    }
+
+    /// the Moore-Penrose pseudo inverse
+    pub fn pinv(&self) -> Result<Matrix<T>, MatrixError> {
+        Ok(self.svd()?.pinv())
+    }
+
+    /// the 2-norm condition number
+    pub fn cond(&self) -> Result<T, MatrixError> {
+        Ok(self.svd()?.cond())
+    }
+
+    /// the spectral norm, the largest singular value
+    pub fn norm2(&self) -> Result<T, MatrixError> {
+        Ok(self.svd()?.norm2())
+    }
+}
+
+impl<T: InVector> Svd<T>
+    where T: Float {
+    /// the number of singular values greater than `tol`
+    pub fn rank(&self, tol: T) -> usize {
+        self.singular_values.iter().filter(|&&s| s > tol).count()
+    }
+
+    /// the Moore-Penrose pseudo inverse, singular values below
+    /// `max(rows, cols) * epsilon * norm2()` are treated as zero
+    pub fn pinv(&self) -> Matrix<T> {
+        let rows = self.u.rows();
+        let cols = self.v_t.cols();
+        let cutoff = T::from(rows.max(cols)).unwrap() * T::epsilon() * self.norm2();
+        let mut pinv = Matrix::new(cols, rows);
+
+        for (k, &s) in self.singular_values.iter().enumerate() {
+            if s <= cutoff {
+                continue
+            }
+
+            for i in 0..cols {
+                for j in 0..rows {
+                    pinv[(i, j)] = pinv[(i, j)] + self.v_t[(k, i)] * self.u[(j, k)] / s;
+                }
+            }
+        }
+
+        pinv
+    }
+
+    /// the 2-norm condition number, the ratio of the largest and smallest singular values
+    pub fn cond(&self) -> T {
+        match self.singular_values.last() {
+            Some(&min) => self.norm2() / min,
+            None => T::zero()
+        }
+    }
+
+    /// the spectral norm, the largest singular value
+    pub fn norm2(&self) -> T {
+        self.singular_values.first().cloned().unwrap_or_else(T::zero)
+    }
+}
@@ -0,0 +1,299 @@
+use super::*;
+
+type MatrixI = Matrix<i32>;
+type VectorI = ::VectorI32;
+
+#[test]
+fn init() {
+    let m = MatrixI::new(2, 3);
+
+    assert_eq!(m.dim(), (2, 3));
+    assert_eq!(m.into_vec(), vec![0; 6])
+}
+
+#[test]
+fn identity() {
+    let m = MatrixI::identity(2);
+
+    assert_eq!(m.into_vec(), vec![1, 0, 0, 1])
+}
+
+#[test]
+fn index() {
+    let m = MatrixI::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(m[(0, 2)], 3);
+    assert_eq!(m[(1, 0)], 4);
+    assert_eq!(m.row(1), &[4, 5, 6]);
+    assert_eq!(m.col_vector(1), VectorI::from(vec![2, 5]))
+}
+
+#[test]
+fn from_rows() {
+    let m = MatrixI::from_rows(vec![VectorI::from(vec![1, 2]), VectorI::from(vec![3, 4])]);
+
+    assert_eq!(m, MatrixI::from_vec(2, 2, vec![1, 2, 3, 4]))
+}
+
+#[test]
+fn transpose() {
+    let m = MatrixI::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(m.transpose(), MatrixI::from_vec(3, 2, vec![1, 4, 2, 5, 3, 6]))
+}
+
+#[test]
+fn elementwise() {
+    let a = MatrixI::from_vec(2, 2, vec![1, 2, 3, 4]);
+    let b = MatrixI::from_vec(2, 2, vec![5, 6, 7, 8]);
+
+    assert_eq!(&a + &b, MatrixI::from_vec(2, 2, vec![6, 8, 10, 12]));
+    assert_eq!(&b - &a, MatrixI::from_vec(2, 2, vec![4, 4, 4, 4]));
+    assert_eq!(a * 2, MatrixI::from_vec(2, 2, vec![2, 4, 6, 8]))
+}
+
+#[test]
+#[should_panic]
+fn elementwise_mismatch() {
+    let a = MatrixI::new(2, 2);
+    let b = MatrixI::new(2, 3);
+
+    let _ = a + b;
+}
+
+#[test]
+fn matrix_product() {
+    let a = MatrixI::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let b = MatrixI::from_vec(3, 2, vec![7, 8, 9, 10, 11, 12]);
+
+    assert_eq!(&a * &b, Ok(MatrixI::from_vec(2, 2, vec![58, 64, 139, 154])));
+    assert_eq!(&a * &a, Err(ShapeMismatch { left: (2, 3), right: (2, 3) }));
+
+    use std::error::Error;
+
+    let boxed: Box<dyn Error> = (&a * &a).unwrap_err().into();
+    assert_eq!(boxed.to_string(), "can not multiply a 2x3 by a 2x3");
+
+    let boxed: Box<dyn Error> = MatrixError::Singular.into();
+    assert_eq!(boxed.to_string(), "matrix is singular")
+}
+
+#[test]
+fn matrix_vector_product() {
+    let a = MatrixI::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let v = VectorI::from(vec![1, 0, 2]);
+    let w = VectorI::from(vec![1, 1]);
+
+    assert_eq!(&a * &v, Ok(VectorI::from(vec![7, 16])));
+    assert_eq!(&w * &a, Ok(VectorI::from(vec![5, 7, 9])));
+    assert_eq!(&a * &w, Err(ShapeMismatch { left: (2, 3), right: (2, 1) }));
+
+    let empty = MatrixI::new(3, 0);
+    assert_eq!(&empty * &VectorI::new(0), Ok(VectorI::new(3)));
+    assert_eq!(&VectorI::new(3) * &empty, Ok(VectorI::new(0)))
+}
+
+#[test]
+fn iter_rows() {
+    let mut m = MatrixI::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(m.iter_rows().collect::<Vec<_>>(), vec![&[1, 2][..], &[3, 4], &[5, 6]]);
+    assert_eq!(m.iter_rows().rev().next(), Some(&[5, 6][..]));
+
+    for (i, row) in m.iter_rows_mut().rev().enumerate() {
+        row[0] = i as i32;
+    }
+    assert_eq!(m, MatrixI::from_vec(3, 2, vec![2, 2, 1, 4, 0, 6]));
+
+    let mut empty = MatrixI::new(3, 0);
+    assert_eq!(empty.iter_rows().len(), 3);
+    assert!(empty.iter_rows().all(|row| row.is_empty()));
+    assert_eq!(empty.iter_rows_mut().count(), 3);
+    assert_eq!(MatrixI::new(0, 2).iter_rows().count(), 0)
+}
+
+#[test]
+fn serde() {
+    use serde_test::{Token, assert_tokens, assert_de_tokens_error};
+
+    fn tokens(rows: u64, cols: u64, data: &[i32]) -> Vec<Token> {
+        let mut tokens = vec![Token::Struct { name: "Matrix", len: 3 },
+                              Token::Str("rows"), Token::U64(rows),
+                              Token::Str("cols"), Token::U64(cols),
+                              Token::Str("data"), Token::Seq { len: Some(data.len()) }];
+        tokens.extend(data.iter().map(|&x| Token::I32(x)));
+        tokens.extend(vec![Token::SeqEnd, Token::StructEnd]);
+        tokens
+    }
+
+    assert_tokens(&MatrixI::from_vec(2, 1, vec![1, 2]), &tokens(2, 1, &[1, 2]));
+    assert_tokens(&MatrixI::new(2, 0), &tokens(2, 0, &[]));
+    assert_de_tokens_error::<MatrixI>(&tokens(2, 2, &[1, 2, 3]), "data does not fit a 2x2 matrix");
+    assert_de_tokens_error::<MatrixI>(&tokens(1 << 32, 1 << 32, &[]), "data does not fit a 4294967296x4294967296 matrix")
+}
+
+type MatrixD = Matrix<f64>;
+type VectorD = ::VectorF64;
+
+fn assert_close(a: &[f64], b: &[f64]) {
+    assert_eq!(a.len(), b.len());
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert!((x - y).abs() < 1e-9, "{:?} != {:?}", a, b);
+    }
+}
+
+#[test]
+fn lu() {
+    let a = MatrixD::from_vec(3, 3, vec![2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0]);
+    let lu = a.lu().unwrap();
+    let pa = (lu.p() * &a).unwrap();
+    let l_u = (lu.l() * lu.u()).unwrap();
+
+    assert_close(pa.as_slice(), l_u.as_slice());
+    assert!((lu.det() - -16.0).abs() < 1e-9)
+}
+
+#[test]
+fn solve() {
+    let a = MatrixD::from_vec(3, 3, vec![2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0]);
+    let b = VectorD::from(vec![5.0, -2.0, 9.0]);
+
+    assert_close(&a.solve(&b).unwrap(), &[1.0, 1.0, 2.0]);
+    assert_eq!(a.solve(&VectorD::new(2)).unwrap_err(),
+               MatrixError::Shape(ShapeMismatch { left: (3, 3), right: (2, 1) }))
+}
+
+#[test]
+fn inverse() {
+    let a = MatrixD::from_vec(2, 2, vec![4.0, 7.0, 2.0, 6.0]);
+    let inv = a.inverse().unwrap();
+
+    assert_close((&a * &inv).unwrap().as_slice(), MatrixD::identity(2).as_slice());
+    assert_eq!(MatrixD::from_vec(2, 2, vec![1.0, 2.0, 2.0, 4.0]).inverse(), Err(MatrixError::Singular));
+    assert_eq!(MatrixD::new(2, 3).det(), Err(MatrixError::NotSquare { rows: 2, cols: 3 }))
+}
+
+#[test]
+fn qr() {
+    let a = MatrixD::from_vec(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let qr = a.qr();
+    let q = qr.q();
+    let r = qr.r();
+
+    assert_close((&q * &r).unwrap().as_slice(), a.as_slice());
+    assert_close((q.transpose() * &q).unwrap().as_slice(), MatrixD::identity(3).as_slice());
+    assert!(r[(1, 0)].abs() < 1e-9 && r[(2, 0)].abs() < 1e-9 && r[(2, 1)].abs() < 1e-9)
+}
+
+#[test]
+fn lstsq() {
+    // fit a line through points that do not lie on one
+    let a = MatrixD::from_vec(4, 2, vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0]);
+    let b = VectorD::from(vec![1.5, 2.5, 5.5, 6.5]);
+    let (x, residual) = a.lstsq(&b).unwrap();
+    let fitted = (&a * &x).unwrap();
+
+    assert_close(&x, &[1.3, 1.8]);
+    assert!((residual - (b - fitted).mag()).abs() < 1e-9);
+    assert_eq!(a.transpose().lstsq(&VectorD::new(2)).unwrap_err(),
+               MatrixError::Underdetermined { rows: 2, cols: 4 })
+}
+
+#[test]
+fn cholesky() {
+    let a = MatrixD::from_vec(3, 3, vec![4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0]);
+    let chol = a.cholesky().unwrap();
+    let l = chol.l();
+
+    assert_close(l.as_slice(), &[2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0]);
+    assert_close(&chol.solve(&VectorD::from(vec![4.0, 12.0, -16.0])).unwrap(), &[1.0, 0.0, 0.0]);
+    assert!((chol.log_det() - 36.0f64.ln()).abs() < 1e-9);
+    assert_eq!(MatrixD::from_vec(2, 2, vec![1.0, 2.0, 2.0, 1.0]).cholesky(), Err(MatrixError::NotPositiveDefinite))
+}
+
+#[test]
+fn symmetric_eigen() {
+    let a = MatrixD::from_vec(3, 3, vec![2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0]);
+    let eigen = a.symmetric_eigen().unwrap();
+    let root2 = 2.0f64.sqrt();
+
+    assert_close(&eigen.values, &[2.0 - root2, 2.0, 2.0 + root2]);
+
+    for i in 0..3 {
+        let v = eigen.vectors.col_vector(i);
+        let av = (&a * &v).unwrap();
+
+        assert_close(&av, &(&v * eigen.values[i]));
+        assert!((v.mag() - 1.0).abs() < 1e-9);
+    }
+
+    assert_eq!(a.symmetric_eigen_with(0.0, 0), Err(MatrixError::NoConvergence { iterations: 0 }))
+}
+
+#[test]
+fn large_symmetric_eigen() {
+    use num::traits::Float;
+
+    // a dense symmetric matrix with entries spread over [-1, 1]
+    fn check<T: Float + InVector + ::std::fmt::Debug>(n: usize, tol: T) {
+        let a = Matrix::from_vec(n, n, (0..n * n).map(|k| {
+            let (i, j) = (k / n, k % n);
+            T::from(((i * j + i + j) as f64 * 0.37).sin()).unwrap()
+        }).collect());
+        let eigen = a.symmetric_eigen().unwrap();
+
+        for i in 0..n {
+            let v = eigen.vectors.col_vector(i);
+            let residual = (&(&a * &v).unwrap() - &(&v * eigen.values[i])).mag();
+
+            assert!(residual < tol, "residual {:?} for eigenvalue {}", residual, i);
+        }
+    }
+
+    check::<f64>(60, 1e-10);
+    check::<f64>(80, 1e-10);
+    check::<f32>(80, 1e-3)
+}
+
+#[test]
+fn svd() {
+    let a = MatrixD::from_vec(2, 3, vec![3.0, 2.0, 2.0, 2.0, 3.0, -2.0]);
+    let svd = a.svd().unwrap();
+    let svd::Svd { ref u, ref singular_values, ref v_t } = svd;
+
+    assert_close(singular_values, &[5.0, 3.0]);
+    assert_eq!((u.dim(), v_t.dim()), ((2, 2), (2, 3)));
+
+    let mut s = MatrixD::new(2, 2);
+    s[(0, 0)] = singular_values[0];
+    s[(1, 1)] = singular_values[1];
+    let usv = ((u * &s).unwrap() * v_t).unwrap();
+
+    assert_close(usv.as_slice(), a.as_slice());
+    assert!((svd.norm2() - 5.0).abs() < 1e-9);
+    assert!((svd.cond() - 5.0 / 3.0).abs() < 1e-9);
+    assert_eq!(svd.rank(1e-9), 2)
+}
+
+#[test]
+fn pinv() {
+    let a = MatrixD::from_vec(3, 2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+    let pinv = a.pinv().unwrap();
+    let apa = ((&a * &pinv).unwrap() * &a).unwrap();
+
+    assert_eq!(a.rank(1e-9), Ok(1));
+    assert_eq!(pinv.dim(), (2, 3));
+    assert_close(apa.as_slice(), a.as_slice())
+}
+
+#[test]
+fn col_view() {
+    let mut m = MatrixI::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(m.col_view(1).to_vector(), VectorI::from(vec![2, 4, 6]));
+    assert_eq!(m.col_view(0).dot(&m.col_view(1)), 1 * 2 + 3 * 4 + 5 * 6);
+    assert_eq!(m.row_view(1).to_vector(), VectorI::from(vec![3, 4]));
+
+    m.col_view_mut(0).fill(0);
+    assert_eq!(m, MatrixI::from_vec(3, 2, vec![0, 2, 0, 4, 0, 6]))
+}
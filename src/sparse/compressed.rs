@@ -0,0 +1,158 @@
+//! the algorithms shared by `CsrMatrix` and `CscMatrix`, written in terms of a major axis
+//! (rows for CSR, columns for CSC) whose entries are stored contiguously, and a minor axis
+
+use std::prelude::v1::*;
+
+use num::traits::Zero;
+
+/// `(ptr, indices, values)` of a compressed matrix
+pub type Parts<T> = (Vec<usize>, Vec<usize>, Vec<T>);
+
+/// panics if the parts do not describe a valid compressed matrix
+pub fn check(major: usize, minor: usize, ptr: &[usize], indices: &[usize], nnz: usize) {
+    if let Err(message) = validate(major, minor, ptr, indices, nnz) {
+        panic!("{}", message)
+    }
+}
+
+/// checks that the parts describe a valid compressed matrix, returning what is wrong otherwise
+pub fn validate(major: usize, minor: usize, ptr: &[usize], indices: &[usize], nnz: usize) -> Result<(), &'static str> {
+    if major.checked_add(1) != Some(ptr.len()) {
+        return Err("the pointer array needs one more entry than there are lanes")
+    }
+
+    if indices.len() != nnz {
+        return Err("every index needs exactly one value")
+    }
+
+    if ptr[0] != 0 {
+        return Err("the pointer array must start at 0")
+    }
+
+    if ptr[major] != nnz {
+        return Err("the pointer array must end at the number of entries")
+    }
+
+    // with an ascending pointer array every lane lies within the indices
+    if !ptr.windows(2).all(|lane| lane[0] <= lane[1]) {
+        return Err("the pointer array must be ascending")
+    }
+
+    for lane in ptr.windows(2) {
+        let lane = &indices[lane[0]..lane[1]];
+
+        if !lane.windows(2).all(|w| w[0] < w[1]) {
+            return Err("indices within a lane must be strictly ascending")
+        }
+
+        if !lane.last().map_or(true, |&i| i < minor) {
+            return Err("index out of bounds")
+        }
+    }
+
+    Ok(())
+}
+
+/// compresses `(major, minor, value)` triplets, summing duplicates
+pub fn compress<T: Zero>(major: usize, mut entries: Vec<(usize, usize, T)>) -> Parts<T> {
+    entries.sort_by_key(|&(i, j, _)| (i, j));
+
+    let mut ptr = vec![0; major + 1];
+    let mut indices: Vec<usize> = Vec::with_capacity(entries.len());
+    let mut values: Vec<T> = Vec::with_capacity(entries.len());
+    let mut last = None;
+
+    for (i, j, v) in entries {
+        if last == Some((i, j)) {
+            let sum = values.pop().unwrap() + v;
+            values.push(sum);
+        } else {
+            ptr[i + 1] += 1;
+            indices.push(j);
+            values.push(v);
+            last = Some((i, j));
+        }
+    }
+
+    for i in 0..major {
+        ptr[i + 1] += ptr[i];
+    }
+
+    (ptr, indices, values)
+}
+
+/// swaps the major and minor axes, converting between CSR and CSC
+pub fn transpose<T: Clone>(minor: usize, ptr: &[usize], indices: &[usize], values: &[T]) -> Parts<T> {
+    let mut new_ptr = vec![0; minor + 1];
+
+    for &j in indices {
+        new_ptr[j + 1] += 1;
+    }
+
+    for j in 0..minor {
+        new_ptr[j + 1] += new_ptr[j];
+    }
+
+    // walking the old lanes in order keeps the new indices ascending
+    let mut next = new_ptr.clone();
+    let mut slots: Vec<Option<(usize, T)>> = vec![None; indices.len()];
+
+    for i in 0..ptr.len() - 1 {
+        for k in ptr[i]..ptr[i + 1] {
+            let j = indices[k];
+            slots[next[j]] = Some((i, values[k].clone()));
+            next[j] += 1;
+        }
+    }
+
+    let (new_indices, new_values) = slots.into_iter().map(|slot| slot.unwrap()).unzip();
+
+    (new_ptr, new_indices, new_values)
+}
+
+/// the stored value at `(major, minor)`
+pub fn get<'a, T>(ptr: &[usize], indices: &[usize], values: &'a [T], major: usize, minor: usize) -> Option<&'a T> {
+    let (start, end) = (ptr[major], ptr[major + 1]);
+
+    indices[start..end].binary_search(&minor).ok().map(|k| &values[start + k])
+}
+
+/// merges two compressed matrices of the same shape lane by lane, `both` combines entries
+/// stored in both and `right` maps entries stored only in `b`, zero results are not stored
+pub fn merge<T, F, G>(a: (&[usize], &[usize], &[T]), b: (&[usize], &[usize], &[T]), both: F, right: G) -> Parts<T>
+    where T: Zero + Clone, F: Fn(T, T) -> T, G: Fn(T) -> T {
+    let ((a_ptr, a_idx, a_val), (b_ptr, b_idx, b_val)) = (a, b);
+    let mut ptr = Vec::with_capacity(a_ptr.len());
+    let mut indices = Vec::with_capacity(a_idx.len() + b_idx.len());
+    let mut values = Vec::with_capacity(a_idx.len() + b_idx.len());
+
+    ptr.push(0);
+
+    for lane in 0..a_ptr.len() - 1 {
+        let (mut p, a_end) = (a_ptr[lane], a_ptr[lane + 1]);
+        let (mut q, b_end) = (b_ptr[lane], b_ptr[lane + 1]);
+
+        while p < a_end || q < b_end {
+            let (index, value) = if q == b_end || (p < a_end && a_idx[p] < b_idx[q]) {
+                p += 1;
+                (a_idx[p - 1], a_val[p - 1].clone())
+            } else if p == a_end || b_idx[q] < a_idx[p] {
+                q += 1;
+                (b_idx[q - 1], right(b_val[q - 1].clone()))
+            } else {
+                p += 1;
+                q += 1;
+                (a_idx[p - 1], both(a_val[p - 1].clone(), b_val[q - 1].clone()))
+            };
+
+            if !value.is_zero() {
+                indices.push(index);
+                values.push(value);
+            }
+        }
+
+        ptr.push(indices.len());
+    }
+
+    (ptr, indices, values)
+}
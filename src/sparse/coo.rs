@@ -0,0 +1,159 @@
+use super::{CooMatrix, CsrMatrix, CscMatrix, InVector};
+use super::compressed;
+use matrix::Matrix;
+
+use std::prelude::v1::*;
+
+use num::traits::Zero;
+
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+
+impl<T: InVector> CooMatrix<T> {
+    /// creates an empty `rows` by `cols` matrix
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self::with_capacity(rows, cols, 0)
+    }
+
+    /// creates an empty `rows` by `cols` matrix with room for `capacity` entries
+    pub fn with_capacity(rows: usize, cols: usize, capacity: usize) -> Self {
+        CooMatrix {
+            rows, cols,
+            row_indices: Vec::with_capacity(capacity),
+            col_indices: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity)
+        }
+    }
+
+    /// adds an entry, entries at the same position are summed on conversion,
+    /// panics if the position is out of bounds
+    pub fn push(&mut self, row: usize, col: usize, value: T) {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        self.row_indices.push(row);
+        self.col_indices.push(col);
+        self.values.push(value);
+    }
+
+    /// the number of rows in the matrix
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// the number of columns in the matrix
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// the shape of the matrix as `(rows, cols)`
+    pub fn dim(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// the number of stored entries, including duplicates
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// iterates over the stored entries as `(row, col, value)` in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.row_indices.iter()
+            .zip(self.col_indices.iter())
+            .zip(self.values.iter())
+            .map(|((&i, &j), v)| (i, j, v))
+    }
+
+    /// swaps the rows and columns, this only exchanges the index arrays
+    pub fn transpose(self) -> Self {
+        CooMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_indices: self.col_indices,
+            col_indices: self.row_indices,
+            values: self.values
+        }
+    }
+
+    /// compresses into row-major form, summing duplicate entries
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    where T: Zero + Clone {
+        let entries = self.iter().map(|(i, j, v)| (i, j, v.clone())).collect();
+        let (row_ptr, col_indices, values) = compressed::compress(self.rows, entries);
+
+        CsrMatrix { rows: self.rows, cols: self.cols, row_ptr, col_indices, values }
+    }
+
+    /// compresses into column-major form, summing duplicate entries
+    pub fn to_csc(&self) -> CscMatrix<T>
+    where T: Zero + Clone {
+        let entries = self.iter().map(|(i, j, v)| (j, i, v.clone())).collect();
+        let (col_ptr, row_indices, values) = compressed::compress(self.cols, entries);
+
+        CscMatrix { rows: self.rows, cols: self.cols, col_ptr, row_indices, values }
+    }
+
+    /// converts to a dense matrix, summing duplicate entries
+    pub fn to_dense(&self) -> Matrix<T>
+    where T: Zero + Clone {
+        let mut dense: Matrix<T> = Matrix::new(self.rows, self.cols);
+
+        for (i, j, v) in self.iter() {
+            dense[(i, j)] = dense[(i, j)].clone() + v.clone();
+        }
+
+        dense
+    }
+}
+
+impl<'a, T: InVector + Zero + Clone> From<&'a Matrix<T>> for CooMatrix<T> {
+    // keeps only the nonzero entries of a dense matrix
+    fn from(value: &'a Matrix<T>) -> Self {
+        let mut coo = CooMatrix::new(value.rows(), value.cols());
+
+        for (k, v) in value.iter().enumerate() {
+            if !v.is_zero() {
+                coo.push(k / value.cols(), k % value.cols(), v.clone());
+            }
+        }
+
+        coo
+    }
+}
+
+impl<T: InVector + Zero + Clone> From<CooMatrix<T>> for CsrMatrix<T> {
+    fn from(value: CooMatrix<T>) -> Self {
+        value.to_csr()
+    }
+}
+
+impl<T: InVector + Zero + Clone> From<CooMatrix<T>> for CscMatrix<T> {
+    fn from(value: CooMatrix<T>) -> Self {
+        value.to_csc()
+    }
+}
+
+impl<'de, T: InVector + Deserialize<'de>> Deserialize<'de> for CooMatrix<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        #[serde(rename = "CooMatrix")]
+        struct Parts<T> {
+            rows: usize,
+            cols: usize,
+            row_indices: Vec<usize>,
+            col_indices: Vec<usize>,
+            values: Vec<T>
+        }
+
+        let Parts { rows, cols, row_indices, col_indices, values } = Parts::deserialize(deserializer)?;
+
+        if row_indices.len() != values.len() || col_indices.len() != values.len() {
+            return Err(D::Error::custom("every entry needs a row, a column and a value"))
+        }
+
+        if !row_indices.iter().zip(&col_indices).all(|(&i, &j)| i < rows && j < cols) {
+            return Err(D::Error::custom("index out of bounds"))
+        }
+
+        Ok(CooMatrix { rows, cols, row_indices, col_indices, values })
+    }
+}
@@ -0,0 +1,253 @@
+use super::{CooMatrix, CsrMatrix, CscMatrix, SparseVector, InVector};
+use super::compressed;
+use matrix::{Matrix, ShapeMismatch};
+use vector::Vector;
+
+use std::prelude::v1::*;
+use std::ops::*;
+
+use num::traits::Zero;
+
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+
+impl<T: InVector> CscMatrix<T> {
+    /// creates a matrix from its compressed parts, panics if they are inconsistent
+    pub fn from_parts(rows: usize, cols: usize, col_ptr: Vec<usize>, row_indices: Vec<usize>, values: Vec<T>) -> Self {
+        compressed::check(cols, rows, &col_ptr, &row_indices, values.len());
+        CscMatrix { rows, cols, col_ptr, row_indices, values }
+    }
+
+    /// creates a `rows` by `cols` matrix with no stored entries
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        CscMatrix { rows, cols, col_ptr: vec![0; cols + 1], row_indices: Vec::new(), values: Vec::new() }
+    }
+
+    /// the number of rows in the matrix
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// the number of columns in the matrix
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// the shape of the matrix as `(rows, cols)`
+    pub fn dim(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// the number of stored entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// the offsets of each column into `row_indices` and `values`
+    pub fn col_ptr(&self) -> &[usize] {
+        &self.col_ptr
+    }
+
+    /// the row of every stored entry
+    pub fn row_indices(&self) -> &[usize] {
+        &self.row_indices
+    }
+
+    /// the value of every stored entry
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// the stored value at `(row, col)`, or `None` if it is an implicit zero
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        compressed::get(&self.col_ptr, &self.row_indices, &self.values, col, row)
+    }
+
+    /// copies a column of the matrix into a sparse vector
+    pub fn col(&self, col: usize) -> SparseVector<T>
+    where T: Clone {
+        assert!(col < self.cols, "column index out of bounds");
+        let range = self.col_ptr[col]..self.col_ptr[col + 1];
+
+        SparseVector::from_parts(self.rows, self.row_indices[range.clone()].to_vec(), self.values[range].to_vec())
+    }
+
+    /// copies the main diagonal of the matrix into a dense vector, missing entries are zero
+    pub fn diagonal(&self) -> Vector<T>
+    where T: Zero + Clone {
+        Vector((0..self.rows.min(self.cols)).map(|i| {
+            compressed::get(&self.col_ptr, &self.row_indices, &self.values, i, i).cloned().unwrap_or_else(T::zero)
+        }).collect())
+    }
+
+    /// iterates over the stored entries as `(row, col, value)` in column-major order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        let col_ptr = &self.col_ptr;
+
+        (0..self.cols).flat_map(move |j| (col_ptr[j]..col_ptr[j + 1]).map(move |k| (j, k)))
+                      .map(move |(j, k)| (self.row_indices[k], j, &self.values[k]))
+    }
+
+    /// the transposed matrix
+    pub fn transpose(&self) -> CscMatrix<T>
+    where T: Clone {
+        let (col_ptr, row_indices, values) = compressed::transpose(self.rows, &self.col_ptr, &self.row_indices, &self.values);
+
+        CscMatrix { rows: self.cols, cols: self.rows, col_ptr, row_indices, values }
+    }
+
+    /// converts to row-major form
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    where T: Clone {
+        let (row_ptr, col_indices, values) = compressed::transpose(self.rows, &self.col_ptr, &self.row_indices, &self.values);
+
+        CsrMatrix { rows: self.rows, cols: self.cols, row_ptr, col_indices, values }
+    }
+
+    /// converts to coordinate form
+    pub fn to_coo(&self) -> CooMatrix<T>
+    where T: Clone {
+        let mut coo = CooMatrix::with_capacity(self.rows, self.cols, self.nnz());
+
+        for (i, j, v) in self.iter() {
+            coo.push(i, j, v.clone());
+        }
+
+        coo
+    }
+
+    /// converts to a dense matrix
+    pub fn to_dense(&self) -> Matrix<T>
+    where T: Zero + Clone {
+        let mut dense: Matrix<T> = Matrix::new(self.rows, self.cols);
+
+        for (i, j, v) in self.iter() {
+            dense[(i, j)] = v.clone();
+        }
+
+        dense
+    }
+
+    /// maps the stored values according to the function provided, implicit zeros are left alone
+    pub fn map_ref<U: InVector, F>(&self, f: F) -> CscMatrix<U>
+        where F: Fn(&T) -> U {
+        CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            col_ptr: self.col_ptr.clone(),
+            row_indices: self.row_indices.clone(),
+            values: self.values.iter().map(f).collect()
+        }
+    }
+}
+
+fn mul_csc_vector<T, U, O>(lhs: &CscMatrix<T>, rhs: &Vector<U>) -> Result<Vector<O>, ShapeMismatch>
+    where T: InVector + Clone + Mul<U, Output = O>,
+          U: InVector + Clone, O: InVector + Zero {
+    if lhs.cols != rhs.dim() {
+        return Err(ShapeMismatch { left: lhs.dim(), right: (rhs.dim(), 1) })
+    }
+
+    // scatters each column scaled by the matching element of `rhs`
+    let mut out: Vec<O> = (0..lhs.rows).map(|_| O::zero()).collect();
+
+    for j in 0..lhs.cols {
+        for k in lhs.col_ptr[j]..lhs.col_ptr[j + 1] {
+            let i = lhs.row_indices[k];
+            let product = lhs.values[k].clone() * rhs[j].clone();
+            out[i] = ::std::mem::replace(&mut out[i], O::zero()) + product;
+        }
+    }
+
+    Ok(Vector(out))
+}
+
+fn merge_csc<T, F, G>(lhs: &CscMatrix<T>, rhs: &CscMatrix<T>, both: F, right: G) -> CscMatrix<T>
+    where T: InVector + Zero + Clone, F: Fn(T, T) -> T, G: Fn(T) -> T {
+    assert_eq!(lhs.dim(), rhs.dim());
+    let (col_ptr, row_indices, values) = compressed::merge(
+        (&lhs.col_ptr, &lhs.row_indices, &lhs.values),
+        (&rhs.col_ptr, &rhs.row_indices, &rhs.values),
+        both, right
+    );
+
+    CscMatrix { rows: lhs.rows, cols: lhs.cols, col_ptr, row_indices, values }
+}
+
+macro_rules! impl_csc_op {
+    (@mul [$($lt:tt)*] $Lhs:ty, $Rhs:ty) => {
+        impl<$($lt)* T, U, O> Mul<$Rhs> for $Lhs
+            where T: InVector + Clone + Mul<U, Output = O>,
+                  U: InVector + Clone, O: InVector + Zero {
+            type Output = Result<Vector<O>, ShapeMismatch>;
+
+            fn mul(self, rhs: $Rhs) -> Self::Output {
+                mul_csc_vector(&self, &rhs)
+            }
+        }
+    };
+    (@merge [$($lt:tt)*] $Lhs:ty, $Rhs:ty => $Op:ident, $func:ident, $right:expr, $($bound:tt)*) => {
+        impl<$($lt)* T> $Op<$Rhs> for $Lhs
+            where T: InVector + Zero + Clone $($bound)* {
+            type Output = CscMatrix<T>;
+
+            fn $func(self, rhs: $Rhs) -> Self::Output {
+                merge_csc(&self, &rhs, |a, b| a.$func(b), $right)
+            }
+        }
+    };
+    ($Op:ident, $func:ident, $right:expr => $($bound:tt)*) => {
+        impl_csc_op!(@merge [] CscMatrix<T>, CscMatrix<T> => $Op, $func, $right, $($bound)*);
+        impl_csc_op!(@merge ['a,] CscMatrix<T>, &'a CscMatrix<T> => $Op, $func, $right, $($bound)*);
+        impl_csc_op!(@merge ['a,] &'a CscMatrix<T>, CscMatrix<T> => $Op, $func, $right, $($bound)*);
+        impl_csc_op!(@merge ['a,] &'a CscMatrix<T>, &'a CscMatrix<T> => $Op, $func, $right, $($bound)*);
+    };
+}
+
+impl_csc_op!(@mul [] CscMatrix<T>, Vector<U>);
+impl_csc_op!(@mul ['a,] CscMatrix<T>, &'a Vector<U>);
+impl_csc_op!(@mul ['a,] &'a CscMatrix<T>, Vector<U>);
+impl_csc_op!(@mul ['a,] &'a CscMatrix<T>, &'a Vector<U>);
+
+impl_csc_op!(Add, add, |b| b => );
+impl_csc_op!(Sub, sub, |b| T::zero() - b => + Sub<Output = T>);
+
+impl<'a, T: InVector + Zero + Clone> From<&'a Matrix<T>> for CscMatrix<T> {
+    // keeps only the nonzero entries of a dense matrix
+    fn from(value: &'a Matrix<T>) -> Self {
+        CooMatrix::from(value).to_csc()
+    }
+}
+
+impl<'a, T: InVector + Clone> From<&'a CsrMatrix<T>> for CscMatrix<T> {
+    fn from(value: &'a CsrMatrix<T>) -> Self {
+        value.to_csc()
+    }
+}
+
+impl<'a, T: InVector + Clone> From<&'a CscMatrix<T>> for CsrMatrix<T> {
+    fn from(value: &'a CscMatrix<T>) -> Self {
+        value.to_csr()
+    }
+}
+
+impl<'de, T: InVector + Deserialize<'de>> Deserialize<'de> for CscMatrix<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        #[serde(rename = "CscMatrix")]
+        struct Parts<T> {
+            rows: usize,
+            cols: usize,
+            col_ptr: Vec<usize>,
+            row_indices: Vec<usize>,
+            values: Vec<T>
+        }
+
+        let Parts { rows, cols, col_ptr, row_indices, values } = Parts::deserialize(deserializer)?;
+        compressed::validate(cols, rows, &col_ptr, &row_indices, values.len()).map_err(D::Error::custom)?;
+
+        Ok(CscMatrix { rows, cols, col_ptr, row_indices, values })
+    }
+}
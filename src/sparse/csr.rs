@@ -0,0 +1,234 @@
+use super::{CooMatrix, CsrMatrix, CscMatrix, SparseVector, InVector};
+use super::compressed;
+use matrix::{Matrix, ShapeMismatch};
+use vector::Vector;
+
+use std::prelude::v1::*;
+use std::ops::*;
+
+use num::traits::Zero;
+
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+
+impl<T: InVector> CsrMatrix<T> {
+    /// creates a matrix from its compressed parts, panics if they are inconsistent
+    pub fn from_parts(rows: usize, cols: usize, row_ptr: Vec<usize>, col_indices: Vec<usize>, values: Vec<T>) -> Self {
+        compressed::check(rows, cols, &row_ptr, &col_indices, values.len());
+        CsrMatrix { rows, cols, row_ptr, col_indices, values }
+    }
+
+    /// creates a `rows` by `cols` matrix with no stored entries
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        CsrMatrix { rows, cols, row_ptr: vec![0; rows + 1], col_indices: Vec::new(), values: Vec::new() }
+    }
+
+    /// the number of rows in the matrix
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// the number of columns in the matrix
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// the shape of the matrix as `(rows, cols)`
+    pub fn dim(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// the number of stored entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// the offsets of each row into `col_indices` and `values`
+    pub fn row_ptr(&self) -> &[usize] {
+        &self.row_ptr
+    }
+
+    /// the column of every stored entry
+    pub fn col_indices(&self) -> &[usize] {
+        &self.col_indices
+    }
+
+    /// the value of every stored entry
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// the stored value at `(row, col)`, or `None` if it is an implicit zero
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        compressed::get(&self.row_ptr, &self.col_indices, &self.values, row, col)
+    }
+
+    /// copies a row of the matrix into a sparse vector
+    pub fn row(&self, row: usize) -> SparseVector<T>
+    where T: Clone {
+        assert!(row < self.rows, "row index out of bounds");
+        let range = self.row_ptr[row]..self.row_ptr[row + 1];
+
+        SparseVector::from_parts(self.cols, self.col_indices[range.clone()].to_vec(), self.values[range].to_vec())
+    }
+
+    /// copies the main diagonal of the matrix into a dense vector, missing entries are zero
+    pub fn diagonal(&self) -> Vector<T>
+    where T: Zero + Clone {
+        Vector((0..self.rows.min(self.cols)).map(|i| {
+            compressed::get(&self.row_ptr, &self.col_indices, &self.values, i, i).cloned().unwrap_or_else(T::zero)
+        }).collect())
+    }
+
+    /// iterates over the stored entries as `(row, col, value)` in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        let row_ptr = &self.row_ptr;
+
+        (0..self.rows).flat_map(move |i| (row_ptr[i]..row_ptr[i + 1]).map(move |k| (i, k)))
+                      .map(move |(i, k)| (i, self.col_indices[k], &self.values[k]))
+    }
+
+    /// the transposed matrix
+    pub fn transpose(&self) -> CsrMatrix<T>
+    where T: Clone {
+        let (row_ptr, col_indices, values) = compressed::transpose(self.cols, &self.row_ptr, &self.col_indices, &self.values);
+
+        CsrMatrix { rows: self.cols, cols: self.rows, row_ptr, col_indices, values }
+    }
+
+    /// converts to column-major form
+    pub fn to_csc(&self) -> CscMatrix<T>
+    where T: Clone {
+        let (col_ptr, row_indices, values) = compressed::transpose(self.cols, &self.row_ptr, &self.col_indices, &self.values);
+
+        CscMatrix { rows: self.rows, cols: self.cols, col_ptr, row_indices, values }
+    }
+
+    /// converts to coordinate form
+    pub fn to_coo(&self) -> CooMatrix<T>
+    where T: Clone {
+        let mut coo = CooMatrix::with_capacity(self.rows, self.cols, self.nnz());
+
+        for (i, j, v) in self.iter() {
+            coo.push(i, j, v.clone());
+        }
+
+        coo
+    }
+
+    /// converts to a dense matrix
+    pub fn to_dense(&self) -> Matrix<T>
+    where T: Zero + Clone {
+        let mut dense: Matrix<T> = Matrix::new(self.rows, self.cols);
+
+        for (i, j, v) in self.iter() {
+            dense[(i, j)] = v.clone();
+        }
+
+        dense
+    }
+
+    /// maps the stored values according to the function provided, implicit zeros are left alone
+    pub fn map_ref<U: InVector, F>(&self, f: F) -> CsrMatrix<U>
+        where F: Fn(&T) -> U {
+        CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            row_ptr: self.row_ptr.clone(),
+            col_indices: self.col_indices.clone(),
+            values: self.values.iter().map(f).collect()
+        }
+    }
+}
+
+fn mul_csr_vector<T, U, O>(lhs: &CsrMatrix<T>, rhs: &Vector<U>) -> Result<Vector<O>, ShapeMismatch>
+    where T: InVector + Clone + Mul<U, Output = O>,
+          U: InVector + Clone, O: InVector + Zero {
+    if lhs.cols != rhs.dim() {
+        return Err(ShapeMismatch { left: lhs.dim(), right: (rhs.dim(), 1) })
+    }
+
+    Ok(Vector((0..lhs.rows).map(|i| {
+        (lhs.row_ptr[i]..lhs.row_ptr[i + 1]).fold(O::zero(), |acc, k| {
+            acc + lhs.values[k].clone() * rhs[lhs.col_indices[k]].clone()
+        })
+    }).collect()))
+}
+
+fn merge_csr<T, F, G>(lhs: &CsrMatrix<T>, rhs: &CsrMatrix<T>, both: F, right: G) -> CsrMatrix<T>
+    where T: InVector + Zero + Clone, F: Fn(T, T) -> T, G: Fn(T) -> T {
+    assert_eq!(lhs.dim(), rhs.dim());
+    let (row_ptr, col_indices, values) = compressed::merge(
+        (&lhs.row_ptr, &lhs.col_indices, &lhs.values),
+        (&rhs.row_ptr, &rhs.col_indices, &rhs.values),
+        both, right
+    );
+
+    CsrMatrix { rows: lhs.rows, cols: lhs.cols, row_ptr, col_indices, values }
+}
+
+macro_rules! impl_csr_op {
+    (@mul [$($lt:tt)*] $Lhs:ty, $Rhs:ty) => {
+        impl<$($lt)* T, U, O> Mul<$Rhs> for $Lhs
+            where T: InVector + Clone + Mul<U, Output = O>,
+                  U: InVector + Clone, O: InVector + Zero {
+            type Output = Result<Vector<O>, ShapeMismatch>;
+
+            fn mul(self, rhs: $Rhs) -> Self::Output {
+                mul_csr_vector(&self, &rhs)
+            }
+        }
+    };
+    (@merge [$($lt:tt)*] $Lhs:ty, $Rhs:ty => $Op:ident, $func:ident, $right:expr, $($bound:tt)*) => {
+        impl<$($lt)* T> $Op<$Rhs> for $Lhs
+            where T: InVector + Zero + Clone $($bound)* {
+            type Output = CsrMatrix<T>;
+
+            fn $func(self, rhs: $Rhs) -> Self::Output {
+                merge_csr(&self, &rhs, |a, b| a.$func(b), $right)
+            }
+        }
+    };
+    ($Op:ident, $func:ident, $right:expr => $($bound:tt)*) => {
+        impl_csr_op!(@merge [] CsrMatrix<T>, CsrMatrix<T> => $Op, $func, $right, $($bound)*);
+        impl_csr_op!(@merge ['a,] CsrMatrix<T>, &'a CsrMatrix<T> => $Op, $func, $right, $($bound)*);
+        impl_csr_op!(@merge ['a,] &'a CsrMatrix<T>, CsrMatrix<T> => $Op, $func, $right, $($bound)*);
+        impl_csr_op!(@merge ['a,] &'a CsrMatrix<T>, &'a CsrMatrix<T> => $Op, $func, $right, $($bound)*);
+    };
+}
+
+impl_csr_op!(@mul [] CsrMatrix<T>, Vector<U>);
+impl_csr_op!(@mul ['a,] CsrMatrix<T>, &'a Vector<U>);
+impl_csr_op!(@mul ['a,] &'a CsrMatrix<T>, Vector<U>);
+impl_csr_op!(@mul ['a,] &'a CsrMatrix<T>, &'a Vector<U>);
+
+impl_csr_op!(Add, add, |b| b => );
+impl_csr_op!(Sub, sub, |b| T::zero() - b => + Sub<Output = T>);
+
+impl<'a, T: InVector + Zero + Clone> From<&'a Matrix<T>> for CsrMatrix<T> {
+    // keeps only the nonzero entries of a dense matrix
+    fn from(value: &'a Matrix<T>) -> Self {
+        CooMatrix::from(value).to_csr()
+    }
+}
+
+impl<'de, T: InVector + Deserialize<'de>> Deserialize<'de> for CsrMatrix<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        #[serde(rename = "CsrMatrix")]
+        struct Parts<T> {
+            rows: usize,
+            cols: usize,
+            row_ptr: Vec<usize>,
+            col_indices: Vec<usize>,
+            values: Vec<T>
+        }
+
+        let Parts { rows, cols, row_ptr, col_indices, values } = Parts::deserialize(deserializer)?;
+        compressed::validate(rows, cols, &row_ptr, &col_indices, values.len()).map_err(D::Error::custom)?;
+
+        Ok(CsrMatrix { rows, cols, row_ptr, col_indices, values })
+    }
+}
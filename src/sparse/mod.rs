@@ -0,0 +1,59 @@
+pub mod vector_impl;
+pub mod vector_ops;
+pub mod coo;
+pub mod csr;
+pub mod csc;
+mod compressed;
+
+#[cfg(test)]
+mod tests;
+
+use super::InVector;
+use std::prelude::v1::*;
+
+/// a sparse vector storing only its nonzero entries, as indices in ascending order and their values
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct SparseVector<T: InVector> {
+    dim: usize,
+    indices: Vec<usize>,
+    values: Vec<T>
+}
+
+impl<T> !InVector for SparseVector<T> {}
+
+/// a sparse matrix in coordinate format, a list of `(row, col, value)` triplets in any order,
+/// used to assemble a matrix before converting it to `CsrMatrix` or `CscMatrix`
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct CooMatrix<T: InVector> {
+    rows: usize,
+    cols: usize,
+    row_indices: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<T>
+}
+
+/// a sparse matrix in compressed sparse row format, the entries of row `i` are stored
+/// in `row_ptr[i]..row_ptr[i + 1]` with their columns in ascending order
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct CsrMatrix<T: InVector> {
+    rows: usize,
+    cols: usize,
+    row_ptr: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<T>
+}
+
+/// a sparse matrix in compressed sparse column format, the entries of column `j` are stored
+/// in `col_ptr[j]..col_ptr[j + 1]` with their rows in ascending order
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct CscMatrix<T: InVector> {
+    rows: usize,
+    cols: usize,
+    col_ptr: Vec<usize>,
+    row_indices: Vec<usize>,
+    values: Vec<T>
+}
+
+impl<T> !InVector for CooMatrix<T> {}
+impl<T> !InVector for CsrMatrix<T> {}
+impl<T> !InVector for CscMatrix<T> {}
@@ -0,0 +1,202 @@
+use super::*;
+use vector::Vector;
+
+type SparseI = SparseVector<i32>;
+type VectorI = ::VectorI32;
+
+#[test]
+fn from_dense() {
+    let dense = VectorI::from(vec![0, 3, 0, 0, -1, 0]);
+    let sparse = SparseI::from(&dense);
+
+    assert_eq!(sparse.dim(), 6);
+    assert_eq!(sparse.nnz(), 2);
+    assert_eq!(sparse.indices(), &[1, 4]);
+    assert_eq!(sparse.get(4), Some(&-1));
+    assert_eq!(sparse.get(0), None);
+    assert_eq!(Vector::from(sparse), dense)
+}
+
+#[test]
+fn insert_remove() {
+    let mut v = SparseI::new(5);
+    v.insert(3, 1);
+    v.insert(1, 2);
+    v.insert(3, 4);
+
+    assert_eq!(v, SparseI::from_parts(5, vec![1, 3], vec![2, 4]));
+    assert_eq!(v.remove(1), Some(2));
+    assert_eq!(v.remove(1), None);
+    assert_eq!(v.nnz(), 1)
+}
+
+#[test]
+#[should_panic]
+fn unsorted_parts() {
+    SparseI::from_parts(5, vec![3, 1], vec![1, 2]);
+}
+
+#[test]
+fn sparse_dot() {
+    let a = SparseI::from_parts(6, vec![0, 2, 5], vec![1, 2, 3]);
+    let b = SparseI::from_parts(6, vec![2, 3, 5], vec![4, 5, 6]);
+    let dense = VectorI::from(vec![1, 1, 1, 1, 1, 2]);
+
+    assert_eq!(a.dot(&b), 2 * 4 + 3 * 6);
+    assert_eq!(a.dot_dense(&dense), 1 + 2 + 3 * 2);
+    assert_eq!(a.magsq(), 14)
+}
+
+#[test]
+fn sparse_add_sub() {
+    let a = SparseI::from_parts(6, vec![0, 2, 5], vec![1, 2, 3]);
+    let b = SparseI::from_parts(6, vec![2, 3, 5], vec![4, 5, 3]);
+
+    assert_eq!(&a + &b, SparseI::from_parts(6, vec![0, 2, 3, 5], vec![1, 6, 5, 6]));
+    assert_eq!(a.clone() + &b, &a + b.clone());
+    assert_eq!(&b - a.clone(), -(a.clone() - &b));
+    assert_eq!(&a - &b, SparseI::from_parts(6, vec![0, 2, 3], vec![1, -2, -5]));
+    assert_eq!((a.clone() - a.clone()).nnz(), 0);
+    assert_eq!(&a * 2, SparseI::from_parts(6, vec![0, 2, 5], vec![2, 4, 6]));
+    assert_eq!(-&a, SparseI::from_parts(6, vec![0, 2, 5], vec![-1, -2, -3]))
+}
+
+#[test]
+fn sparse_serde() {
+    use serde_test::{Token, assert_tokens, assert_de_tokens_error};
+
+    fn tokens(dim: u64, indices: &[u64], values: &[i32]) -> Vec<Token> {
+        let mut tokens = vec![Token::Struct { name: "SparseVector", len: 3 },
+                              Token::Str("dim"), Token::U64(dim),
+                              Token::Str("indices"), Token::Seq { len: Some(indices.len()) }];
+        tokens.extend(indices.iter().map(|&i| Token::U64(i)));
+        tokens.extend(vec![Token::SeqEnd, Token::Str("values"), Token::Seq { len: Some(values.len()) }]);
+        tokens.extend(values.iter().map(|&x| Token::I32(x)));
+        tokens.extend(vec![Token::SeqEnd, Token::StructEnd]);
+        tokens
+    }
+
+    assert_tokens(&SparseI::from_parts(4, vec![1, 3], vec![5, 6]), &tokens(4, &[1, 3], &[5, 6]));
+    assert_de_tokens_error::<SparseI>(&tokens(4, &[1, 3], &[5]), "every index needs exactly one value");
+    assert_de_tokens_error::<SparseI>(&tokens(4, &[3, 1], &[5, 6]), "indices must be strictly ascending");
+    assert_de_tokens_error::<SparseI>(&tokens(4, &[1, 1], &[5, 6]), "indices must be strictly ascending");
+    assert_de_tokens_error::<SparseI>(&tokens(3, &[1, 3], &[5, 6]), "index out of bounds")
+}
+
+#[test]
+fn sparse_matrix_serde() {
+    use serde_test::{Token, assert_tokens, assert_de_tokens_error};
+
+    // a struct of two sizes followed by three sequences, like every sparse matrix format
+    fn tokens(name: &'static str, fields: [&'static str; 3], (rows, cols): (u64, u64),
+              first: &[u64], second: &[u64], values: &[i32]) -> Vec<Token> {
+        let mut tokens = vec![Token::Struct { name, len: 5 },
+                              Token::Str("rows"), Token::U64(rows),
+                              Token::Str("cols"), Token::U64(cols)];
+
+        for (&field, seq) in fields[..2].iter().zip(&[first, second]) {
+            tokens.extend(vec![Token::Str(field), Token::Seq { len: Some(seq.len()) }]);
+            tokens.extend(seq.iter().map(|&i| Token::U64(i)));
+            tokens.push(Token::SeqEnd);
+        }
+
+        tokens.extend(vec![Token::Str(fields[2]), Token::Seq { len: Some(values.len()) }]);
+        tokens.extend(values.iter().map(|&x| Token::I32(x)));
+        tokens.extend(vec![Token::SeqEnd, Token::StructEnd]);
+        tokens
+    }
+
+    let csr_fields = ["row_ptr", "col_indices", "values"];
+    let csr = |ptr: &[u64], indices: &[u64], values: &[i32]| tokens("CsrMatrix", csr_fields, (2, 3), ptr, indices, values);
+
+    let valid = CsrMatrix::from_parts(2, 3, vec![0, 2, 4], vec![0, 2, 1, 2], vec![1, 2, 3, 4]);
+    assert_tokens(&valid, &csr(&[0, 2, 4], &[0, 2, 1, 2], &[1, 2, 3, 4]));
+    assert_de_tokens_error::<CsrMatrix<i32>>(&csr(&[0, 2], &[0, 2], &[1, 2]), "the pointer array needs one more entry than there are lanes");
+    assert_de_tokens_error::<CsrMatrix<i32>>(&csr(&[0, 3, 2], &[0, 1], &[1, 2]), "the pointer array must be ascending");
+    assert_de_tokens_error::<CsrMatrix<i32>>(&csr(&[0, 1, 2], &[0, 3], &[1, 2]), "index out of bounds");
+    assert_de_tokens_error::<CsrMatrix<i32>>(&csr(&[0, 2, 2], &[2, 0], &[1, 2]), "indices within a lane must be strictly ascending");
+    assert_de_tokens_error::<CsrMatrix<i32>>(&csr(&[0, 1, 2], &[0, 1], &[1]), "every index needs exactly one value");
+
+    let csc = tokens("CscMatrix", ["col_ptr", "row_indices", "values"], (2, 3), &[0, 1, 1, 2], &[1, 2], &[5, 6]);
+    assert_de_tokens_error::<CscMatrix<i32>>(&csc, "index out of bounds");
+
+    let coo = |rows: &[u64], cols: &[u64], values: &[i32]| {
+        tokens("CooMatrix", ["row_indices", "col_indices", "values"], (2, 3), rows, cols, values)
+    };
+
+    let mut m = CooMatrix::new(2, 3);
+    m.push(1, 2, 7);
+    assert_tokens(&m, &coo(&[1], &[2], &[7]));
+    assert_de_tokens_error::<CooMatrix<i32>>(&coo(&[1, 0], &[2], &[7, 8]), "every entry needs a row, a column and a value");
+    assert_de_tokens_error::<CooMatrix<i32>>(&coo(&[2], &[0], &[7]), "index out of bounds");
+    assert_de_tokens_error::<CooMatrix<i32>>(&coo(&[0], &[3], &[7]), "index out of bounds")
+}
+
+fn sample() -> CooMatrix<i32> {
+    // [1 0 2]
+    // [0 0 3]
+    // [4 5 0]
+    let mut coo = CooMatrix::new(3, 3);
+    coo.push(2, 1, 5);
+    coo.push(0, 0, 1);
+    coo.push(1, 2, 3);
+    coo.push(0, 2, 1);
+    coo.push(2, 0, 4);
+    coo.push(0, 2, 1);
+    coo
+}
+
+#[test]
+fn coo_to_compressed() {
+    let csr = sample().to_csr();
+    let csc = sample().to_csc();
+
+    assert_eq!(csr, CsrMatrix::from_parts(3, 3, vec![0, 2, 3, 5], vec![0, 2, 2, 0, 1], vec![1, 2, 3, 4, 5]));
+    assert_eq!(csc, CscMatrix::from_parts(3, 3, vec![0, 2, 3, 5], vec![0, 2, 2, 0, 1], vec![1, 4, 5, 2, 3]));
+    assert_eq!(csr.get(0, 2), Some(&2));
+    assert_eq!(csc.get(1, 1), None);
+    assert_eq!(csr.to_csc(), csc);
+    assert_eq!(csc.to_csr(), csr);
+    assert_eq!(csr.to_dense(), sample().to_dense());
+    assert_eq!(CsrMatrix::from(&csr.to_dense()), csr);
+    assert_eq!(csr.row(2), SparseI::from_parts(3, vec![0, 1], vec![4, 5]))
+}
+
+#[test]
+#[should_panic]
+fn unsorted_csr_parts() {
+    CsrMatrix::from_parts(2, 3, vec![0, 2, 2], vec![2, 0], vec![1, 2]);
+}
+
+#[test]
+fn sparse_transpose() {
+    let csr = sample().to_csr();
+
+    assert_eq!(csr.transpose().to_dense(), csr.to_dense().transpose());
+    assert_eq!(csr.to_csc().transpose().to_dense(), csr.to_dense().transpose());
+    assert_eq!(sample().transpose().to_csr(), csr.transpose())
+}
+
+#[test]
+fn sparse_matrix_vector() {
+    let csr = sample().to_csr();
+    let csc = sample().to_csc();
+    let v = VectorI::from(vec![1, 2, 3]);
+    let expected = (csr.to_dense() * &v).unwrap();
+
+    assert_eq!((&csr * &v).unwrap(), expected);
+    assert_eq!((&csc * &v).unwrap(), expected);
+    assert!((&csr * VectorI::from(vec![1, 2])).is_err())
+}
+
+#[test]
+fn sparse_matrix_add_sub() {
+    let a = sample().to_csr();
+    let b = CsrMatrix::from_parts(3, 3, vec![0, 1, 2, 3], vec![1, 2, 0], vec![7, -3, 1]);
+
+    assert_eq!((&a + &b).to_dense(), &a.to_dense() + &b.to_dense());
+    assert_eq!((&a - &b).to_dense(), &a.to_dense() - &b.to_dense());
+    assert_eq!((&a + &b).nnz(), 5);
+    assert_eq!((a.clone() - a.clone()).nnz(), 0);
+    assert_eq!((a.to_csc() + b.to_csc()).to_csr(), &a + &b)
+}
@@ -0,0 +1,239 @@
+use super::{SparseVector, InVector};
+use vector::Vector;
+
+use std::prelude::v1::*;
+use std::ops::Mul;
+use std::iter::Zip;
+use std::slice;
+
+use num::traits::*;
+
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+
+impl<T: InVector> SparseVector<T> {
+    /// creates a sparse vector of dimension `dim` with no nonzero entries
+    pub fn new(dim: usize) -> Self {
+        SparseVector { dim, indices: Vec::new(), values: Vec::new() }
+    }
+
+    /// creates a sparse vector from its entries, panics if the indices are
+    /// not strictly ascending, out of bounds or a different length than the values
+    pub fn from_parts(dim: usize, indices: Vec<usize>, values: Vec<T>) -> Self {
+        assert_eq!(indices.len(), values.len(), "every index needs exactly one value");
+        assert!(indices.windows(2).all(|w| w[0] < w[1]), "indices must be strictly ascending");
+        assert!(indices.last().map_or(true, |&i| i < dim), "index out of bounds");
+
+        SparseVector { dim, indices, values }
+    }
+
+    /// the dimension of the vector, including the implicit zeros
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// the number of stored entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// the indices of the stored entries in ascending order
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// the values of the stored entries, in the same order as `indices`
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// iterates over the stored entries as `(index, value)` pairs in ascending order of index
+    pub fn iter(&self) -> Zip<slice::Iter<'_, usize>, slice::Iter<'_, T>> {
+        self.indices.iter().zip(self.values.iter())
+    }
+
+    /// the stored value at `index`, or `None` if it is an implicit zero
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.indices.binary_search(&index).ok().map(|i| &self.values[i])
+    }
+
+    /// sets the value at `index`, replacing any stored value, panics if `index` is out of bounds
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index < self.dim, "index out of bounds");
+
+        match self.indices.binary_search(&index) {
+            Ok(i) => self.values[i] = value,
+            Err(i) => {
+                self.indices.insert(i, index);
+                self.values.insert(i, value);
+            }
+        }
+    }
+
+    /// removes the stored value at `index`, returning it if there was one
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        match self.indices.binary_search(&index) {
+            Ok(i) => {
+                self.indices.remove(i);
+                Some(self.values.remove(i))
+            },
+            Err(_) => None
+        }
+    }
+
+    /// maps the stored values according to the function provided, implicit zeros are left alone
+    pub fn map<U: InVector, F>(self, f: F) -> SparseVector<U>
+        where F: Fn(T) -> U {
+        SparseVector { dim: self.dim, indices: self.indices, values: self.values.into_iter().map(f).collect() }
+    }
+
+    /// maps the stored values according to the function provided, implicit zeros are left alone
+    pub fn map_ref<U: InVector, F>(&self, f: F) -> SparseVector<U>
+        where F: Fn(&T) -> U {
+        SparseVector { dim: self.dim, indices: self.indices.clone(), values: self.values.iter().map(f).collect() }
+    }
+
+    /// removes stored entries that are zero
+    pub fn prune(&mut self)
+    where T: Zero {
+        let mut keep = 0;
+
+        for i in 0..self.values.len() {
+            if !self.values[i].is_zero() {
+                self.indices.swap(keep, i);
+                self.values.swap(keep, i);
+                keep += 1;
+            }
+        }
+
+        self.indices.truncate(keep);
+        self.values.truncate(keep);
+    }
+
+    /// takes the dot product of the two sparse vectors
+    pub fn dot<U, O>(&self, other: &SparseVector<U>) -> O
+    where U: InVector + Clone,
+          O: InVector + Zero,
+          T: Clone + Mul<U, Output = O> {
+        assert_eq!(self.dim, other.dim);
+        let (mut i, mut j) = (0, 0);
+        let mut sum = O::zero();
+
+        while i < self.nnz() && j < other.nnz() {
+            if self.indices[i] < other.indices[j] {
+                i += 1;
+            } else if self.indices[i] > other.indices[j] {
+                j += 1;
+            } else {
+                sum = sum + self.values[i].clone() * other.values[j].clone();
+                i += 1;
+                j += 1;
+            }
+        }
+
+        sum
+    }
+
+    /// takes the dot product with a dense vector, only touching the stored entries
+    pub fn dot_dense<U, O>(&self, other: &Vector<U>) -> O
+    where U: InVector + Clone,
+          O: InVector + Zero,
+          T: Clone + Mul<U, Output = O> {
+        assert_eq!(self.dim, other.dim());
+        self.iter().fold(O::zero(), |acc, (&i, v)| acc + v.clone() * other[i].clone())
+    }
+
+    /// the square of the magnitude
+    pub fn magsq(&self) -> T
+    where T: Zero + Clone + Mul<Output = T> {
+        self.values.iter().fold(T::zero(), |acc, v| acc + v.clone() * v.clone())
+    }
+
+    /// sums up the stored elements of the vector
+    pub fn sum(&self) -> T
+    where T: Zero + Clone {
+        self.values.iter().fold(T::zero(), |acc, v| acc + v.clone())
+    }
+
+    /// converts to a dense vector, filling in the implicit zeros
+    pub fn to_dense(&self) -> Vector<T>
+    where T: Zero + Clone {
+        let mut dense = Vector::new(self.dim);
+
+        for (&i, v) in self.iter() {
+            dense[i] = v.clone();
+        }
+
+        dense
+    }
+}
+
+impl<T: InVector + Float> SparseVector<T> {
+    /// the magnitude
+    pub fn mag(&self) -> T {
+        self.magsq().sqrt()
+    }
+}
+
+// traits
+impl<T: InVector + Zero> From<Vector<T>> for SparseVector<T> {
+    // keeps only the nonzero entries of a dense vector
+    fn from(value: Vector<T>) -> Self {
+        let dim = value.dim();
+        let (indices, values) = value.into_iter()
+                                     .enumerate()
+                                     .filter(|&(_, ref v)| !v.is_zero())
+                                     .unzip();
+
+        SparseVector { dim, indices, values }
+    }
+}
+
+impl<'a, T: InVector + Zero + Clone> From<&'a Vector<T>> for SparseVector<T> {
+    // keeps only the nonzero entries of a dense vector
+    fn from(value: &'a Vector<T>) -> Self {
+        let (indices, values) = value.iter()
+                                     .enumerate()
+                                     .filter(|&(_, v)| !v.is_zero())
+                                     .map(|(i, v)| (i, v.clone()))
+                                     .unzip();
+
+        SparseVector { dim: value.dim(), indices, values }
+    }
+}
+
+impl<T: InVector + Zero + Clone> From<SparseVector<T>> for Vector<T> {
+    // fills in the implicit zeros
+    fn from(value: SparseVector<T>) -> Self {
+        value.to_dense()
+    }
+}
+
+impl<'de, T: InVector + Deserialize<'de>> Deserialize<'de> for SparseVector<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        #[serde(rename = "SparseVector")]
+        struct Parts<T> {
+            dim: usize,
+            indices: Vec<usize>,
+            values: Vec<T>
+        }
+
+        let Parts { dim, indices, values } = Parts::deserialize(deserializer)?;
+
+        if indices.len() != values.len() {
+            return Err(D::Error::custom("every index needs exactly one value"))
+        }
+
+        if !indices.windows(2).all(|w| w[0] < w[1]) {
+            return Err(D::Error::custom("indices must be strictly ascending"))
+        }
+
+        if !indices.last().map_or(true, |&i| i < dim) {
+            return Err(D::Error::custom("index out of bounds"))
+        }
+
+        Ok(SparseVector { dim, indices, values })
+    }
+}
@@ -0,0 +1,116 @@
+use std::prelude::v1::*;
+use std::ops::*;
+
+use super::{SparseVector, InVector};
+
+use num::traits::Zero;
+
+// merges the entries of two sparse vectors of dimension `dim` by index, `both` combines entries
+// stored in both, `left` and `right` map entries stored in only one, zero results are not stored
+fn merge<T, A, B, F, G, H>(dim: usize, a: A, b: B, both: F, left: G, right: H) -> SparseVector<T>
+    where T: InVector + Zero, A: Iterator<Item = (usize, T)>, B: Iterator<Item = (usize, T)>,
+          F: Fn(T, T) -> T, G: Fn(T) -> T, H: Fn(T) -> T {
+    let capacity = a.size_hint().0 + b.size_hint().0;
+    let mut indices = Vec::with_capacity(capacity);
+    let mut values = Vec::with_capacity(capacity);
+    let (mut a, mut b) = (a.peekable(), b.peekable());
+
+    loop {
+        let (index, value) = match (a.peek().map(|e| e.0), b.peek().map(|e| e.0)) {
+            (Some(i), Some(j)) if i == j => {
+                let (_, x) = a.next().unwrap();
+                let (_, y) = b.next().unwrap();
+                (i, both(x, y))
+            },
+            (Some(i), Some(j)) if i < j => (i, left(a.next().unwrap().1)),
+            (Some(i), None) => (i, left(a.next().unwrap().1)),
+            (_, Some(j)) => (j, right(b.next().unwrap().1)),
+            (None, None) => break
+        };
+
+        if !value.is_zero() {
+            indices.push(index);
+            values.push(value);
+        }
+    }
+
+    SparseVector { dim, indices, values }
+}
+
+// the stored entries of an owned vector
+fn entries<T: InVector>(v: SparseVector<T>) -> impl Iterator<Item = (usize, T)> {
+    v.indices.into_iter().zip(v.values.into_iter())
+}
+
+// the stored entries of a borrowed vector, cloning each value as it is merged
+fn entries_ref<'a, T: InVector + Clone>(v: &'a SparseVector<T>) -> impl Iterator<Item = (usize, T)> + 'a {
+    v.indices.iter().cloned().zip(v.values.iter().cloned())
+}
+
+macro_rules! impl_sparse_op {
+    (@impl [$($lt:tt)*] $Lhs:ty, $Rhs:ty, $lhs:ident, $rhs:ident, $Op:ident, $func:ident, $right:expr => $($bound:tt)*) => {
+        impl<$($lt)* T> $Op<$Rhs> for $Lhs
+            where T: InVector + Zero $($bound)* {
+            type Output = SparseVector<T>;
+
+            fn $func(self, rhs: $Rhs) -> Self::Output {
+                assert_eq!(self.dim, rhs.dim);
+                merge(self.dim, $lhs(self), $rhs(rhs), |a, b| a.$func(b), |a| a, $right)
+            }
+        }
+    };
+    ($Op:ident, $func:ident, $right:expr => $($bound:tt)*) => {
+        impl_sparse_op!(@impl [] SparseVector<T>, SparseVector<T>, entries, entries, $Op, $func, $right => $($bound)*);
+        impl_sparse_op!(@impl ['a,] SparseVector<T>, &'a SparseVector<T>, entries, entries_ref, $Op, $func, $right => + Clone $($bound)*);
+        impl_sparse_op!(@impl ['a,] &'a SparseVector<T>, SparseVector<T>, entries_ref, entries, $Op, $func, $right => + Clone $($bound)*);
+        impl_sparse_op!(@impl ['a,] &'a SparseVector<T>, &'a SparseVector<T>, entries_ref, entries_ref, $Op, $func, $right => + Clone $($bound)*);
+    };
+}
+
+impl_sparse_op!(Add, add, |b| b => );
+impl_sparse_op!(Sub, sub, |b| T::zero() - b => + Sub<Output = T>);
+
+macro_rules! impl_scalar_op {
+    ($Op:ident, $func:ident) => {
+        impl<T, U: Clone, O> $Op<U> for SparseVector<T>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector {
+            type Output = SparseVector<O>;
+
+            fn $func(self, rhs: U) -> Self::Output {
+                self.map(|i| i.$func(rhs.clone()))
+            }
+        }
+
+        impl<'a, T: Clone, U: Clone, O> $Op<U> for &'a SparseVector<T>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector {
+            type Output = SparseVector<O>;
+
+            fn $func(self, rhs: U) -> Self::Output {
+                self.map_ref(|i| i.clone().$func(rhs.clone()))
+            }
+        }
+    };
+}
+
+impl_scalar_op!(Mul, mul);
+impl_scalar_op!(Div, div);
+
+impl<T: InVector, O: InVector> Neg for SparseVector<T>
+where T: Neg<Output = O> {
+    type Output = SparseVector<O>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|x| -x)
+    }
+}
+
+impl<'a, T: InVector + Clone, O: InVector> Neg for &'a SparseVector<T>
+where T: Neg<Output = O> {
+    type Output = SparseVector<O>;
+
+    fn neg(self) -> Self::Output {
+        self.map_ref(|x| -x.clone())
+    }
+}
@@ -0,0 +1,86 @@
+//! compensated and pairwise summation over slices, these lose far less precision than
+//! a naive fold when summing many values of differing magnitude
+
+use num::traits::Float;
+
+/// below this length `sum_pairwise` falls back to a naive sum
+const PAIRWISE_BLOCK: usize = 32;
+
+/// Kahan-Babuska (Neumaier) compensated summation
+pub fn sum_kahan<T: Float>(values: &[T]) -> T {
+    let mut sum = T::zero();
+    let mut comp = T::zero();
+
+    for &x in values {
+        let t = sum + x;
+
+        // recover the low order bits lost from whichever operand is smaller
+        if sum.abs() >= x.abs() {
+            comp = comp + ((sum - t) + x);
+        } else {
+            comp = comp + ((x - t) + sum);
+        }
+
+        sum = t;
+    }
+
+    sum + comp
+}
+
+/// recursively sums each half, the error grows with `log(n)` instead of `n`
+pub fn sum_pairwise<T: Float>(values: &[T]) -> T {
+    if values.len() <= PAIRWISE_BLOCK {
+        values.iter().fold(T::zero(), |acc, &x| acc + x)
+    } else {
+        let (left, right) = values.split_at(values.len() / 2);
+
+        sum_pairwise(left) + sum_pairwise(right)
+    }
+}
+
+/// the dot product computed as if in twice the working precision (Ogita, Rump and Oishi's `Dot2`)
+pub fn dot_compensated<T: Float>(a: &[T], b: &[T]) -> T {
+    assert_eq!(a.len(), b.len());
+
+    let mut sum = T::zero();
+    let mut comp = T::zero();
+
+    for (&x, &y) in a.iter().zip(b.iter()) {
+        // the exact product is `p + p_err` and the exact sum is `t + t_err`
+        let p = x * y;
+        let p_err = x.mul_add(y, -p);
+        let t = sum + p;
+        let z = t - sum;
+        let t_err = (sum - (t - z)) + (p - z);
+
+        sum = t;
+        comp = comp + (p_err + t_err);
+    }
+
+    sum + comp
+}
+
+/// the euclidean norm, scaling as it goes so the squares can not overflow or underflow (like BLAS `nrm2`)
+pub fn nrm2<T: Float>(values: &[T]) -> T {
+    let mut scale = T::zero();
+    let mut ssq = T::one();
+
+    for &x in values {
+        if x.is_zero() {
+            continue
+        }
+
+        let abs = x.abs();
+
+        if scale < abs {
+            let r = scale / abs;
+            ssq = T::one() + ssq * r * r;
+            scale = abs;
+        } else {
+            let r = abs / scale;
+            ssq = ssq + r * r;
+        }
+    }
+
+    scale * ssq.sqrt()
+}
@@ -0,0 +1,56 @@
+use super::{Vector, InVector};
+use error::DimensionMismatch;
+
+use std::ops::*;
+
+use num::traits::Zero;
+
+macro_rules! impl_checked {
+    ($($Op:ident, $func:ident, $checked:ident;)*) => {
+        impl<T: InVector + Clone> Vector<T> {
+            $(
+                /// the element-wise operation, returning an error instead of panicking if the dimensions differ
+                pub fn $checked<U, O>(&self, rhs: &Vector<U>) -> Result<Vector<O>, DimensionMismatch>
+                    where T: $Op<U, Output = O>,
+                          U: InVector + Clone,
+                          O: InVector {
+                    self.check_dim(rhs)?;
+                    Ok($Op::$func(self, rhs))
+                }
+            )*
+        }
+    };
+}
+
+impl_checked! {
+    Add, add, checked_add;
+    Sub, sub, checked_sub;
+    Mul, mul, checked_mul;
+    Div, div, checked_div;
+    Rem, rem, checked_rem;
+    BitAnd, bitand, checked_bitand;
+    BitOr, bitor, checked_bitor;
+    BitXor, bitxor, checked_bitxor;
+    Shl, shl, checked_shl;
+    Shr, shr, checked_shr;
+}
+
+impl<T: InVector> Vector<T> {
+    /// returns an error if the two vectors have different dimensions
+    pub fn check_dim<U: InVector>(&self, other: &Vector<U>) -> Result<(), DimensionMismatch> {
+        if self.dim() == other.dim() {
+            Ok(())
+        } else {
+            Err(DimensionMismatch { left: self.dim(), right: other.dim() })
+        }
+    }
+
+    /// takes the dot product of the two vectors, returning an error instead of panicking if the dimensions differ
+    pub fn checked_dot<U, O>(&self, other: &Vector<U>) -> Result<O, DimensionMismatch>
+    where U: InVector + Clone,
+          O: InVector + Zero,
+          T: InVector + Clone + Mul<U, Output = O> {
+        self.check_dim(other)?;
+        Ok(self.dot(other))
+    }
+}
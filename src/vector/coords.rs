@@ -0,0 +1,48 @@
+use super::{Vector, InVector};
+use coords::{Polar, Spherical, Cylindrical};
+
+use num::traits::Float;
+
+impl<T: InVector + Float> Vector<T> {
+    /// converts a 2 dimensional vector to polar coordinates
+    pub fn to_polar(&self) -> Polar<T> {
+        assert_eq!(self.dim(), 2, "polar coordinates are only defined for 2 dimensional vectors");
+
+        Polar::from_cartesian(self[0], self[1])
+    }
+
+    /// creates a 2 dimensional vector from polar coordinates
+    pub fn from_polar(polar: &Polar<T>) -> Self {
+        let (x, y) = polar.to_cartesian();
+
+        Vector(vec![x, y])
+    }
+
+    /// converts a 3 dimensional vector to spherical coordinates
+    pub fn to_spherical(&self) -> Spherical<T> {
+        assert_eq!(self.dim(), 3, "spherical coordinates are only defined for 3 dimensional vectors");
+
+        Spherical::from_cartesian(self[0], self[1], self[2])
+    }
+
+    /// creates a 3 dimensional vector from spherical coordinates
+    pub fn from_spherical(spherical: &Spherical<T>) -> Self {
+        let (x, y, z) = spherical.to_cartesian();
+
+        Vector(vec![x, y, z])
+    }
+
+    /// converts a 3 dimensional vector to cylindrical coordinates
+    pub fn to_cylindrical(&self) -> Cylindrical<T> {
+        assert_eq!(self.dim(), 3, "cylindrical coordinates are only defined for 3 dimensional vectors");
+
+        Cylindrical::from_cartesian(self[0], self[1], self[2])
+    }
+
+    /// creates a 3 dimensional vector from cylindrical coordinates
+    pub fn from_cylindrical(cylindrical: &Cylindrical<T>) -> Self {
+        let (x, y, z) = cylindrical.to_cartesian();
+
+        Vector(vec![x, y, z])
+    }
+}
@@ -0,0 +1,243 @@
+//! opt-in lazy arithmetic, `v.lazy()` starts an expression whose operators build a tree
+//! instead of allocating, and `eval` or an assignment runs the whole tree in a single pass
+
+use super::{Vector, InVector};
+use super::view::{VectorView, VectorViewMut};
+
+use std::prelude::v1::*;
+use std::ops::*;
+use std::marker::PhantomData;
+
+use num::traits::Zero;
+
+/// an element-wise expression that can be evaluated one index at a time
+pub trait Expr {
+    type Item;
+
+    /// the dimension of the expression's result
+    fn dim(&self) -> usize;
+
+    /// evaluates a single element of the expression
+    fn at(&self, index: usize) -> Self::Item;
+}
+
+/// a binary operation applied element by element
+pub trait BinOp<A, B> {
+    type Output;
+
+    fn apply(a: A, b: B) -> Self::Output;
+}
+
+/// a lazily evaluated expression, the arithmetic operators are implemented on this wrapper
+#[derive(Clone, Copy, Debug)]
+pub struct Lazy<E>(pub E);
+
+/// the element-wise combination of two expressions
+#[derive(Clone, Copy, Debug)]
+pub struct Binary<L, R, Op> {
+    left: L,
+    right: R,
+    op: PhantomData<Op>
+}
+
+/// an expression combined element-wise with a scalar
+#[derive(Clone, Copy, Debug)]
+pub struct Scalar<E, U, Op> {
+    expr: E,
+    value: U,
+    op: PhantomData<Op>
+}
+
+/// an expression with a function applied to every element
+#[derive(Clone, Copy, Debug)]
+pub struct Map<E, F> {
+    expr: E,
+    f: F
+}
+
+impl<E> !InVector for Lazy<E> {}
+
+impl<L: Expr, R: Expr, Op> Expr for Binary<L, R, Op>
+    where Op: BinOp<L::Item, R::Item> {
+    type Item = Op::Output;
+
+    fn dim(&self) -> usize {
+        self.left.dim()
+    }
+
+    fn at(&self, index: usize) -> Self::Item {
+        Op::apply(self.left.at(index), self.right.at(index))
+    }
+}
+
+impl<E: Expr, U: Clone, Op> Expr for Scalar<E, U, Op>
+    where Op: BinOp<E::Item, U> {
+    type Item = Op::Output;
+
+    fn dim(&self) -> usize {
+        self.expr.dim()
+    }
+
+    fn at(&self, index: usize) -> Self::Item {
+        Op::apply(self.expr.at(index), self.value.clone())
+    }
+}
+
+impl<E: Expr, F: Fn(E::Item) -> O, O> Expr for Map<E, F> {
+    type Item = O;
+
+    fn dim(&self) -> usize {
+        self.expr.dim()
+    }
+
+    fn at(&self, index: usize) -> O {
+        (self.f)(self.expr.at(index))
+    }
+}
+
+impl<T: InVector + Clone> Vector<T> {
+    /// starts a lazy expression, nothing is computed until `eval` or an assignment
+    pub fn lazy(&self) -> Lazy<VectorView<'_, T>> {
+        Lazy(self.view())
+    }
+}
+
+impl<'a, T: InVector + Clone> VectorView<'a, T> {
+    /// starts a lazy expression, nothing is computed until `eval` or an assignment
+    pub fn lazy(self) -> Lazy<VectorView<'a, T>> {
+        Lazy(self)
+    }
+}
+
+impl<E: Expr> Lazy<E> {
+    /// the dimension of the expression's result
+    pub fn dim(&self) -> usize {
+        self.0.dim()
+    }
+
+    /// evaluates the expression into a new vector in a single pass
+    pub fn eval(&self) -> Vector<E::Item>
+    where E::Item: InVector {
+        Vector((0..self.dim()).map(|i| self.0.at(i)).collect())
+    }
+
+    /// evaluates the expression directly into `out` without any temporaries
+    pub fn eval_into(&self, mut out: VectorViewMut<E::Item>)
+    where E::Item: InVector {
+        assert_eq!(self.dim(), out.dim());
+        out.iter_mut()
+           .enumerate()
+           .for_each(|(i, x)| *x = self.0.at(i))
+    }
+
+    /// sums up the elements of the expression without evaluating it into a vector
+    pub fn sum(&self) -> E::Item
+    where E::Item: Zero {
+        (0..self.dim()).fold(E::Item::zero(), |acc, i| acc + self.0.at(i))
+    }
+
+    /// lazily applies `f` to every element
+    pub fn map<F: Fn(E::Item) -> O, O>(self, f: F) -> Lazy<Map<E, F>> {
+        Lazy(Map { expr: self.0, f })
+    }
+}
+
+impl<E: Expr> From<Lazy<E>> for Vector<E::Item>
+    where E::Item: InVector {
+    fn from(value: Lazy<E>) -> Self {
+        value.eval()
+    }
+}
+
+macro_rules! impl_lazy_op {
+    (op => $Op:ident, $func:ident, $Marker:ident) => {
+        /// marker for the element-wise operator of the same name
+        #[derive(Clone, Copy, Debug)]
+        pub struct $Marker;
+
+        impl<A: $Op<B>, B> BinOp<A, B> for $Marker {
+            type Output = A::Output;
+
+            fn apply(a: A, b: B) -> Self::Output {
+                $Op::$func(a, b)
+            }
+        }
+
+        impl<E: Expr, F: Expr> $Op<Lazy<F>> for Lazy<E> {
+            type Output = Lazy<Binary<E, F, $Marker>>;
+
+            fn $func(self, rhs: Lazy<F>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                Lazy(Binary { left: self.0, right: rhs.0, op: PhantomData })
+            }
+        }
+
+        impl<'a, E: Expr, U: InVector + Clone> $Op<VectorView<'a, U>> for Lazy<E> {
+            type Output = Lazy<Binary<E, VectorView<'a, U>, $Marker>>;
+
+            fn $func(self, rhs: VectorView<'a, U>) -> Self::Output {
+                self.$func(Lazy(rhs))
+            }
+        }
+
+        impl<'a, E: Expr, U: InVector + Clone> $Op<&'a Vector<U>> for Lazy<E> {
+            type Output = Lazy<Binary<E, VectorView<'a, U>, $Marker>>;
+
+            fn $func(self, rhs: &'a Vector<U>) -> Self::Output {
+                self.$func(rhs.lazy())
+            }
+        }
+
+        impl<'a, T: InVector + Clone, E: Expr> $Op<Lazy<E>> for &'a Vector<T> {
+            type Output = Lazy<Binary<VectorView<'a, T>, E, $Marker>>;
+
+            fn $func(self, rhs: Lazy<E>) -> Self::Output {
+                self.lazy().$func(rhs)
+            }
+        }
+    };
+    (scalar => $Op:ident, $func:ident, $Marker:ident) => {
+        impl<E: Expr, U: InVector + Clone> $Op<U> for Lazy<E> {
+            type Output = Lazy<Scalar<E, U, $Marker>>;
+
+            fn $func(self, rhs: U) -> Self::Output {
+                Lazy(Scalar { expr: self.0, value: rhs, op: PhantomData })
+            }
+        }
+    };
+    (assign => $Op:ident, $func:ident) => {
+        impl<T, E: Expr> $Op<Lazy<E>> for Vector<T>
+            where T: InVector + $Op<E::Item> {
+            fn $func(&mut self, rhs: Lazy<E>) {
+                assert_eq!(self.dim(), rhs.dim());
+                self.iter_mut()
+                    .enumerate()
+                    .for_each(|(i, x)| x.$func(rhs.0.at(i)))
+            }
+        }
+    };
+}
+
+impl_lazy_op!(op => Add, add, AddOp);
+impl_lazy_op!(op => Sub, sub, SubOp);
+impl_lazy_op!(op => Mul, mul, MulOp);
+impl_lazy_op!(op => Div, div, DivOp);
+impl_lazy_op!(op => Rem, rem, RemOp);
+
+impl_lazy_op!(scalar => Mul, mul, MulOp);
+impl_lazy_op!(scalar => Div, div, DivOp);
+
+impl_lazy_op!(assign => AddAssign, add_assign);
+impl_lazy_op!(assign => SubAssign, sub_assign);
+impl_lazy_op!(assign => MulAssign, mul_assign);
+impl_lazy_op!(assign => DivAssign, div_assign);
+impl_lazy_op!(assign => RemAssign, rem_assign);
+
+impl<E: Expr> Neg for Lazy<E>
+    where E::Item: Neg {
+    type Output = Lazy<Map<E, fn(E::Item) -> <E::Item as Neg>::Output>>;
+
+    fn neg(self) -> Self::Output {
+        self.map(Neg::neg as fn(E::Item) -> <E::Item as Neg>::Output)
+    }
+}
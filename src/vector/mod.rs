@@ -1,7 +1,17 @@
 pub mod vector_impl;
 pub mod vector_impl_spec;
 pub mod vector_ops;
+pub mod vector_ops_spec;
+pub mod coords;
+pub mod checked;
+pub mod norms;
+pub mod view;
+pub mod lazy;
 pub mod iter;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+#[cfg(feature = "spec_f32_f64")]
+mod simd;
 
 #[cfg(test)]
 mod tests;
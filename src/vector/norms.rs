@@ -0,0 +1,60 @@
+use super::{Vector, InVector};
+
+use num::traits::Float;
+
+impl<T: InVector + Float> Vector<T> {
+    /// the L1 norm, the sum of the absolute values
+    pub fn norm_l1(&self) -> T {
+        self.iter().fold(T::zero(), |acc, x| acc + x.abs())
+    }
+
+    /// the L-infinity norm, the largest absolute value
+    pub fn norm_inf(&self) -> T {
+        self.iter().fold(T::zero(), |acc, x| acc.max(x.abs()))
+    }
+
+    /// the p-norm, `p` may be infinite
+    pub fn norm_p(&self, p: T) -> T {
+        if p == T::one() {
+            self.norm_l1()
+        } else if p.is_infinite() {
+            self.norm_inf()
+        } else {
+            self.iter().fold(T::zero(), |acc, x| acc + x.abs().powf(p)).powf(p.recip())
+        }
+    }
+
+    /// the weighted euclidean norm `sqrt(sum(w_i * x_i^2))`
+    pub fn norm_weighted(&self, weights: &Self) -> T {
+        assert_eq!(self.dim(), weights.dim());
+        self.iter()
+            .zip(weights.iter())
+            .fold(T::zero(), |acc, (&x, &w)| acc + w * x * x)
+            .sqrt()
+    }
+
+    /// the euclidean distance between two vectors
+    pub fn distance(&self, other: &Self) -> T {
+        (self - other).mag()
+    }
+
+    /// the L1 (manhattan) distance between two vectors
+    pub fn distance_l1(&self, other: &Self) -> T {
+        (self - other).norm_l1()
+    }
+
+    /// the L-infinity (chebyshev) distance between two vectors
+    pub fn distance_inf(&self, other: &Self) -> T {
+        (self - other).norm_inf()
+    }
+
+    /// the p-norm distance between two vectors
+    pub fn distance_p(&self, other: &Self, p: T) -> T {
+        (self - other).norm_p(p)
+    }
+
+    /// the weighted euclidean distance between two vectors
+    pub fn distance_weighted(&self, other: &Self, weights: &Self) -> T {
+        (self - other).norm_weighted(weights)
+    }
+}
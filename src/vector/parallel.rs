@@ -0,0 +1,147 @@
+use super::{Vector, InVector};
+
+use std::prelude::v1::*;
+use std::ops::*;
+
+use rayon::prelude::*;
+use num::traits::Zero;
+
+/// vectors with at least this many elements use the parallel operators
+pub const PARALLEL_THRESHOLD: usize = 1 << 16;
+
+impl<T: InVector + Send> Vector<T> {
+    /// maps the vector's components in parallel according to the function provided
+    pub fn par_map<U: InVector + Send, F>(self, f: F) -> Vector<U>
+        where F: Fn(T) -> U + Sync + Send {
+        Vector(self.0.into_par_iter().map(f).collect())
+    }
+
+    /// sums up the elements of the vector in parallel
+    pub fn par_sum(self) -> T
+        where T: Zero {
+        self.0.into_par_iter().reduce(T::zero, |acc, x| acc + x)
+    }
+}
+
+impl<T: InVector + Sync> Vector<T> {
+    /// maps the vector's components in parallel according to the function provided
+    pub fn par_map_ref<U: InVector + Send, F>(&self, f: F) -> Vector<U>
+        where F: Fn(&T) -> U + Sync + Send {
+        Vector(self.0.par_iter().map(f).collect())
+    }
+
+    /// takes the dot product of the two vectors in parallel
+    pub fn par_dot<U, O>(&self, other: &Vector<U>) -> O
+    where U: InVector + Clone + Sync,
+          O: InVector + Zero + Send,
+          T: Clone + Mul<U, Output = O> {
+        assert_eq!(self.dim(), other.dim());
+        self.0.par_iter()
+              .zip(other.0.par_iter())
+              .map(|(i, j)| i.clone() * j.clone())
+              .reduce(O::zero, |acc, x| acc + x)
+    }
+}
+
+// specializes the operators in `vector_ops` for thread safe element types,
+// switching to rayon once the vectors reach `PARALLEL_THRESHOLD`
+macro_rules! impl_par_op {
+    (own, own => $Op:ident, $func:ident) => {
+        impl<T, U, O> $Op<Vector<U>> for Vector<T>
+            where T: InVector + Send + $Op<U, Output = O>,
+                  U: InVector + Send, O: InVector + Send {
+            default fn $func(self, rhs: Vector<U>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                if self.dim() < PARALLEL_THRESHOLD {
+                    Vector(self.into_iter()
+                               .zip(rhs.into_iter())
+                               .map(|(i, j)| i.$func(j))
+                               .collect())
+                } else {
+                    Vector(self.0.into_par_iter()
+                                 .zip(rhs.0.into_par_iter())
+                                 .map(|(i, j)| i.$func(j))
+                                 .collect())
+                }
+            }
+        }
+    };
+    (own, borrow => $Op:ident, $func:ident) => {
+        impl<'a, T, U: Clone, O> $Op<&'a Vector<U>> for Vector<T>
+            where T: InVector + Send + $Op<U, Output = O>,
+                  U: InVector + Sync, O: InVector + Send {
+            default fn $func(self, rhs: &'a Vector<U>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                if self.dim() < PARALLEL_THRESHOLD {
+                    Vector(self.into_iter()
+                               .zip(rhs.iter())
+                               .map(|(i, j)| i.$func(j.clone()))
+                               .collect())
+                } else {
+                    Vector(self.0.into_par_iter()
+                                 .zip(rhs.0.par_iter())
+                                 .map(|(i, j)| i.$func(j.clone()))
+                                 .collect())
+                }
+            }
+        }
+    };
+    (borrow, own => $Op:ident, $func:ident) => {
+        impl<'a, T: Clone, U, O> $Op<Vector<U>> for &'a Vector<T>
+            where T: InVector + Sync + $Op<U, Output = O>,
+                  U: InVector + Send, O: InVector + Send {
+            default fn $func(self, rhs: Vector<U>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                if self.dim() < PARALLEL_THRESHOLD {
+                    Vector(self.iter()
+                               .zip(rhs.into_iter())
+                               .map(|(i, j)| i.clone().$func(j))
+                               .collect())
+                } else {
+                    Vector(self.0.par_iter()
+                                 .zip(rhs.0.into_par_iter())
+                                 .map(|(i, j)| i.clone().$func(j))
+                                 .collect())
+                }
+            }
+        }
+    };
+    (borrow, borrow => $Op:ident, $func:ident) => {
+        impl<'a, T: Clone, U: Clone, O> $Op<&'a Vector<U>> for &'a Vector<T>
+            where T: InVector + Sync + $Op<U, Output = O>,
+                  U: InVector + Sync, O: InVector + Send {
+            default fn $func(self, rhs: &'a Vector<U>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                if self.dim() < PARALLEL_THRESHOLD {
+                    Vector(self.iter()
+                               .zip(rhs.iter())
+                               .map(|(i, j)| i.clone().$func(j.clone()))
+                               .collect())
+                } else {
+                    Vector(self.0.par_iter()
+                                 .zip(rhs.0.par_iter())
+                                 .map(|(i, j)| i.clone().$func(j.clone()))
+                                 .collect())
+                }
+            }
+        }
+    };
+    (all => $self_type:tt, $other_type:tt) => {
+        impl_par_op!($self_type, $other_type => Add, add);
+        impl_par_op!($self_type, $other_type => Sub, sub);
+        impl_par_op!($self_type, $other_type => Mul, mul);
+        impl_par_op!($self_type, $other_type => Div, div);
+        impl_par_op!($self_type, $other_type => Rem, rem);
+
+        impl_par_op!($self_type, $other_type => BitAnd, bitand);
+        impl_par_op!($self_type, $other_type => BitOr, bitor);
+        impl_par_op!($self_type, $other_type => BitXor, bitxor);
+        impl_par_op!($self_type, $other_type => Shl, shl);
+        impl_par_op!($self_type, $other_type => Shr, shr);
+    };
+}
+
+impl_par_op!(all => own, own);
+impl_par_op!(all => own, borrow);
+impl_par_op!(all => borrow, own);
+impl_par_op!(all => borrow, borrow);
@@ -0,0 +1,179 @@
+//! explicit SIMD kernels for `f32` and `f64` slices, these use AVX when the cpu supports it
+//! and fall back to scalar loops otherwise
+//!
+//! element-wise operations round every lane exactly like the scalar loop does. the reductions
+//! keep one partial sum per lane, the scalar fallback keeps the same partial sums so the result
+//! does not depend on the cpu, but it differs from adding the elements in order
+
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+macro_rules! simd_binary {
+    ($assign:ident, $rev_assign:ident, $raw:ident, $scalar:ident, $avx:ident, $Ty:ty, $lanes:expr,
+     $load:ident, $store:ident, $intrinsic:ident, $op:tt) => {
+        /// `a[i] = a[i] op b[i]`
+        pub fn $assign(a: &mut [$Ty], b: &[$Ty]) {
+            assert_eq!(a.len(), b.len());
+            let out = a.as_mut_ptr();
+            unsafe { $raw(out, b.as_ptr(), out, a.len()) }
+        }
+
+        /// `b[i] = a[i] op b[i]`
+        pub fn $rev_assign(a: &[$Ty], b: &mut [$Ty]) {
+            assert_eq!(a.len(), b.len());
+            let out = b.as_mut_ptr();
+            unsafe { $raw(a.as_ptr(), out, out, a.len()) }
+        }
+
+        // `out` may alias either input, every lane is loaded before it is stored
+        unsafe fn $raw(a: *const $Ty, b: *const $Ty, out: *mut $Ty, len: usize) {
+            #[cfg(target_arch = "x86_64")]
+            {
+                if is_x86_feature_detected!("avx") {
+                    return $avx(a, b, out, len)
+                }
+            }
+
+            $scalar(a, b, out, len)
+        }
+
+        /// the fallback used when AVX is not available, `out[i] = a[i] op b[i]` for `len` elements
+        pub unsafe fn $scalar(a: *const $Ty, b: *const $Ty, out: *mut $Ty, len: usize) {
+            for i in 0..len {
+                *out.add(i) = *a.add(i) $op *b.add(i);
+            }
+        }
+
+        #[cfg(target_arch = "x86_64")]
+        #[target_feature(enable = "avx")]
+        unsafe fn $avx(a: *const $Ty, b: *const $Ty, out: *mut $Ty, len: usize) {
+            let simd_len = len - len % $lanes;
+            let mut i = 0;
+
+            while i < simd_len {
+                $store(out.add(i), $intrinsic($load(a.add(i)), $load(b.add(i))));
+                i += $lanes;
+            }
+
+            for i in simd_len..len {
+                *out.add(i) = *a.add(i) $op *b.add(i);
+            }
+        }
+    };
+}
+
+macro_rules! simd_reduce {
+    ($sum:ident, $dot:ident, $sum_scalar:ident, $dot_scalar:ident, $sum_avx:ident, $dot_avx:ident,
+     $Ty:ty, $lanes:expr, $load:ident, $store:ident, $zero:ident, $add:ident, $mul:ident) => {
+        /// the sum of the elements, added in one partial sum per lane
+        pub fn $sum(a: &[$Ty]) -> $Ty {
+            #[cfg(target_arch = "x86_64")]
+            {
+                if is_x86_feature_detected!("avx") {
+                    return unsafe { $sum_avx(a) }
+                }
+            }
+
+            $sum_scalar(a)
+        }
+
+        /// the dot product of two slices of the same length, added in one partial sum per lane
+        pub fn $dot(a: &[$Ty], b: &[$Ty]) -> $Ty {
+            assert_eq!(a.len(), b.len());
+
+            #[cfg(target_arch = "x86_64")]
+            {
+                if is_x86_feature_detected!("avx") {
+                    return unsafe { $dot_avx(a, b) }
+                }
+            }
+
+            $dot_scalar(a, b)
+        }
+
+        /// the fallback used when AVX is not available, gives the same result as the AVX sum
+        pub fn $sum_scalar(a: &[$Ty]) -> $Ty {
+            let simd_len = a.len() - a.len() % $lanes;
+            let mut lanes = [0.0; $lanes];
+
+            for chunk in a[..simd_len].chunks(lanes.len()) {
+                for (acc, &x) in lanes.iter_mut().zip(chunk) {
+                    *acc += x;
+                }
+            }
+
+            a[simd_len..].iter().fold(lanes.iter().fold(0.0, |acc, &x| acc + x), |acc, &x| acc + x)
+        }
+
+        /// the fallback used when AVX is not available, gives the same result as the AVX dot product
+        pub fn $dot_scalar(a: &[$Ty], b: &[$Ty]) -> $Ty {
+            assert_eq!(a.len(), b.len());
+            let simd_len = a.len() - a.len() % $lanes;
+            let mut lanes = [0.0; $lanes];
+
+            for (x, y) in a[..simd_len].chunks(lanes.len()).zip(b[..simd_len].chunks(lanes.len())) {
+                for (acc, (&x, &y)) in lanes.iter_mut().zip(x.iter().zip(y)) {
+                    *acc += x * y;
+                }
+            }
+
+            a[simd_len..].iter()
+                .zip(b[simd_len..].iter())
+                .fold(lanes.iter().fold(0.0, |acc, &x| acc + x), |acc, (&x, &y)| acc + x * y)
+        }
+
+        #[cfg(target_arch = "x86_64")]
+        #[target_feature(enable = "avx")]
+        unsafe fn $sum_avx(a: &[$Ty]) -> $Ty {
+            let simd_len = a.len() - a.len() % $lanes;
+            let mut acc = $zero();
+            let mut i = 0;
+
+            while i < simd_len {
+                acc = $add(acc, $load(a.as_ptr().add(i)));
+                i += $lanes;
+            }
+
+            let mut lanes = [0.0; $lanes];
+            $store(lanes.as_mut_ptr(), acc);
+
+            a[simd_len..].iter().fold(lanes.iter().fold(0.0, |acc, &x| acc + x), |acc, &x| acc + x)
+        }
+
+        #[cfg(target_arch = "x86_64")]
+        #[target_feature(enable = "avx")]
+        unsafe fn $dot_avx(a: &[$Ty], b: &[$Ty]) -> $Ty {
+            let simd_len = a.len() - a.len() % $lanes;
+            let mut acc = $zero();
+            let mut i = 0;
+
+            // multiplies and adds separately, a fused multiply-add would round differently
+            while i < simd_len {
+                acc = $add(acc, $mul($load(a.as_ptr().add(i)), $load(b.as_ptr().add(i))));
+                i += $lanes;
+            }
+
+            let mut lanes = [0.0; $lanes];
+            $store(lanes.as_mut_ptr(), acc);
+
+            a[simd_len..].iter()
+                .zip(b[simd_len..].iter())
+                .fold(lanes.iter().fold(0.0, |acc, &x| acc + x), |acc, (&x, &y)| acc + x * y)
+        }
+    };
+}
+
+simd_binary!(add_assign_f32, rev_add_assign_f32, add_raw_f32, add_scalar_f32, add_avx_f32, f32, 8, _mm256_loadu_ps, _mm256_storeu_ps, _mm256_add_ps, +);
+simd_binary!(sub_assign_f32, rev_sub_assign_f32, sub_raw_f32, sub_scalar_f32, sub_avx_f32, f32, 8, _mm256_loadu_ps, _mm256_storeu_ps, _mm256_sub_ps, -);
+simd_binary!(mul_assign_f32, rev_mul_assign_f32, mul_raw_f32, mul_scalar_f32, mul_avx_f32, f32, 8, _mm256_loadu_ps, _mm256_storeu_ps, _mm256_mul_ps, *);
+simd_binary!(div_assign_f32, rev_div_assign_f32, div_raw_f32, div_scalar_f32, div_avx_f32, f32, 8, _mm256_loadu_ps, _mm256_storeu_ps, _mm256_div_ps, /);
+
+simd_binary!(add_assign_f64, rev_add_assign_f64, add_raw_f64, add_scalar_f64, add_avx_f64, f64, 4, _mm256_loadu_pd, _mm256_storeu_pd, _mm256_add_pd, +);
+simd_binary!(sub_assign_f64, rev_sub_assign_f64, sub_raw_f64, sub_scalar_f64, sub_avx_f64, f64, 4, _mm256_loadu_pd, _mm256_storeu_pd, _mm256_sub_pd, -);
+simd_binary!(mul_assign_f64, rev_mul_assign_f64, mul_raw_f64, mul_scalar_f64, mul_avx_f64, f64, 4, _mm256_loadu_pd, _mm256_storeu_pd, _mm256_mul_pd, *);
+simd_binary!(div_assign_f64, rev_div_assign_f64, div_raw_f64, div_scalar_f64, div_avx_f64, f64, 4, _mm256_loadu_pd, _mm256_storeu_pd, _mm256_div_pd, /);
+
+simd_reduce!(sum_f32, dot_f32, sum_scalar_f32, dot_scalar_f32, sum_avx_f32, dot_avx_f32, f32, 8,
+             _mm256_loadu_ps, _mm256_storeu_ps, _mm256_setzero_ps, _mm256_add_ps, _mm256_mul_ps);
+simd_reduce!(sum_f64, dot_f64, sum_scalar_f64, dot_scalar_f64, sum_avx_f64, dot_avx_f64, f64, 4,
+             _mm256_loadu_pd, _mm256_storeu_pd, _mm256_setzero_pd, _mm256_add_pd, _mm256_mul_pd);
@@ -46,3 +46,289 @@ fn mag() {
 
     assert_eq!(v1.mag(), (1.0 * 1.0 + 2.0 * 2.0f64).sqrt())
 }
+
+#[test]
+fn polar() {
+    use std::f64::consts::FRAC_PI_2;
+
+    let p = VectorD::from(vec![0.0, 2.0]).to_polar();
+
+    assert_eq!(p.r, 2.0);
+    assert_eq!(p.theta, FRAC_PI_2);
+
+    let v = VectorD::from_polar(&p);
+    assert!((v - VectorD::from(vec![0.0, 2.0])).mag() < 1e-12)
+}
+
+#[test]
+fn spherical_cylindrical() {
+    let v = VectorD::from(vec![1.0, -2.0, 3.0]);
+
+    assert!((VectorD::from_spherical(&v.to_spherical()) - &v).mag() < 1e-12);
+    assert!((VectorD::from_cylindrical(&v.to_cylindrical()) - &v).mag() < 1e-12);
+    assert_eq!(v.to_cylindrical().z, 3.0)
+}
+
+#[test]
+#[should_panic]
+fn polar_wrong_dim() {
+    VectorD::from(vec![1.0, 2.0, 3.0]).to_polar();
+}
+
+#[test]
+fn checked_ops() {
+    use error::{DimensionMismatch, Error};
+    use std::error::Error as StdError;
+
+    let v1 = VectorI::from(vec![1, 2]);
+    let v2 = VectorI::from(vec![4, 9]);
+    let v3 = VectorI::from(vec![1, 2, 3]);
+
+    assert_eq!(v1.checked_add(&v2), Ok(VectorI::from(vec![5, 11])));
+    assert_eq!(v1.checked_dot(&v2), Ok(22));
+    assert_eq!(v1.checked_sub(&v3), Err(DimensionMismatch { left: 2, right: 3 }));
+
+    let err: Error = v3.checked_mul(&v1).unwrap_err().into();
+    assert_eq!(err, Error::DimensionMismatch(DimensionMismatch { left: 3, right: 2 }));
+
+    let boxed: Box<dyn StdError> = v1.checked_dot(&v3).unwrap_err().into();
+    assert_eq!(boxed.to_string(), "dimension mismatch, 2 != 3")
+}
+
+#[test]
+fn norms() {
+    let v1 = VectorD::from(vec![3.0, -4.0]);
+    let v2 = VectorD::from(vec![0.0, 0.0]);
+
+    assert_eq!(v1.norm_l1(), 7.0);
+    assert_eq!(v1.norm_inf(), 4.0);
+    assert_eq!(v1.norm_p(1.0), 7.0);
+    assert!((v1.norm_p(2.0) - 5.0).abs() < 1e-12);
+    assert_eq!(v1.norm_p(::std::f64::INFINITY), 4.0);
+    assert_eq!(v1.norm_weighted(&VectorD::from(vec![1.0, 0.0])), 3.0);
+
+    assert_eq!(v1.distance(&v2), 5.0);
+    assert_eq!(v2.distance_l1(&v1), 7.0);
+    assert_eq!(v2.distance_inf(&v1), 4.0)
+}
+
+#[test]
+fn compensated_sums() {
+    let mut values = vec![1.0f32];
+    values.extend(::std::iter::repeat(1e-8).take(10_000));
+    let v = ::VectorF32::from(values);
+
+    assert_eq!(v.clone().sum(), 1.0);
+    assert!((v.sum_kahan() - 1.0001).abs() < 1e-7);
+    assert!((v.sum_pairwise() - 1.0001).abs() < 1e-6);
+
+    let a = VectorD::from(vec![1e16, 1.0, -1e16]);
+    let b = VectorD::from(vec![1.0, 1.0, 1.0]);
+    assert_eq!(a.dot(&b), 0.0);
+    assert_eq!(a.dot_compensated(&b), 1.0)
+}
+
+#[test]
+fn mag_scaled() {
+    let v = VectorD::from(vec![3e200, 4e200]);
+    let w = VectorD::from(vec![3e-200, 4e-200]);
+
+    assert!(v.mag().is_infinite());
+    assert!((v.mag_scaled() / 5e200 - 1.0).abs() < 1e-12);
+    assert!((w.mag_scaled() / 5e-200 - 1.0).abs() < 1e-12);
+    assert_eq!(VectorD::new(3).mag_scaled(), 0.0)
+}
+
+#[test]
+fn simd_ops() {
+    let a = ::VectorF32::from((0..19).map(|i| i as f32).collect::<Vec<_>>());
+    let b = ::VectorF32::from((0..19).map(|i| (i * 2 + 1) as f32).collect::<Vec<_>>());
+
+    assert_eq!((&a + &b).0, a.iter().zip(b.iter()).map(|(x, y)| x + y).collect::<Vec<_>>());
+    assert_eq!((&a - b.clone()).0, a.iter().zip(b.iter()).map(|(x, y)| x - y).collect::<Vec<_>>());
+    assert_eq!((a.clone() * &b).0, a.iter().zip(b.iter()).map(|(x, y)| x * y).collect::<Vec<_>>());
+    assert_eq!((&b / a.clone()).0, b.iter().zip(a.iter()).map(|(x, y)| x / y).collect::<Vec<_>>());
+
+    assert_eq!(a.dot(&b), (0..19).map(|i| (i * (i * 2 + 1)) as f32).sum::<f32>());
+    assert_eq!(b.magsq(), (0..19).map(|i| ((i * 2 + 1) * (i * 2 + 1)) as f32).sum::<f32>());
+    assert_eq!(a.sum(), 171.0);
+
+    let c = VectorD::from((0..7).map(|i| i as f64).collect::<Vec<_>>());
+    assert_eq!(c.dot(&c), 91.0);
+    assert_eq!((&c - &c).sum(), 0.0)
+}
+
+#[test]
+#[cfg(feature = "spec_f32_f64")]
+fn simd_scalar_fallback() {
+    let a = VectorD::from((0..23).map(|i| (i as f64 * 0.37).sin() * 1e3).collect::<Vec<_>>());
+    let b = VectorD::from((0..23).map(|i| (i as f64 * 0.91).cos() + 2.0).collect::<Vec<_>>());
+    let mut out = vec![0.0; 23];
+
+    unsafe { simd::add_scalar_f64(a.as_ptr(), b.as_ptr(), out.as_mut_ptr(), 23) };
+    assert_eq!(&a + &b, VectorD::from(out.clone()));
+
+    unsafe { simd::sub_scalar_f64(a.as_ptr(), b.as_ptr(), out.as_mut_ptr(), 23) };
+    assert_eq!(&a - &b, VectorD::from(out.clone()));
+
+    unsafe { simd::mul_scalar_f64(a.as_ptr(), b.as_ptr(), out.as_mut_ptr(), 23) };
+    assert_eq!(&a * &b, VectorD::from(out.clone()));
+
+    unsafe { simd::div_scalar_f64(b.as_ptr(), a.as_ptr(), out.as_mut_ptr(), 23) };
+    assert_eq!(&b / &a, VectorD::from(out));
+
+    let x = ::VectorF32::from((0..21).map(|i| 1.0 / (i + 1) as f32).collect::<Vec<_>>());
+    let mut out = vec![0.0; 21];
+
+    unsafe { simd::mul_scalar_f32(x.as_ptr(), x.as_ptr(), out.as_mut_ptr(), 21) };
+    assert_eq!(&x * &x, ::VectorF32::from(out));
+
+    // the SIMD reductions add in a different order than `sum`, but the same on every cpu
+    assert_eq!(a.sum_simd(), simd::sum_scalar_f64(&a));
+    assert_eq!(a.dot_simd(&b), simd::dot_scalar_f64(&a, &b));
+    assert_eq!(x.sum_simd(), simd::sum_scalar_f32(&x));
+    assert_eq!(x.magsq_simd(), simd::dot_scalar_f32(&x, &x));
+
+    assert!((a.sum_simd() - a.clone().sum()).abs() < 1e-9);
+    assert!((a.dot_simd(&b) - a.dot(&b)).abs() < 1e-9);
+    assert!((x.sum_simd() - x.clone().sum()).abs() < 1e-5);
+    assert!((x.magsq_simd() - x.magsq()).abs() < 1e-5);
+    assert_eq!(VectorD::new(0).sum_simd(), 0.0)
+}
+
+#[test]
+#[cfg(feature = "parallel")]
+fn parallel() {
+    use super::parallel::PARALLEL_THRESHOLD;
+
+    let n = PARALLEL_THRESHOLD + 3;
+    let a = ::VectorI64::from((0..n as i64).collect::<Vec<_>>());
+    let b = a.par_map_ref(|x| x * 2);
+
+    assert_eq!(b, a.map_ref(|x| x * 2));
+    assert_eq!(&b - &a, a);
+    assert_eq!((a.clone() + b.clone()).0[n - 1], 3 * (n as i64 - 1));
+    assert_eq!(a.par_dot(&a), a.dot(&a));
+    assert_eq!(b.par_sum(), a.sum() * 2);
+
+    // the f32 and f64 operators split their SIMD kernels across threads above the threshold too
+    let x = VectorD::from((0..n).map(|i| i as f64 * 0.5).collect::<Vec<_>>());
+    let y = VectorD::from((0..n).map(|i| (i % 7) as f64 + 1.0).collect::<Vec<_>>());
+    let expected = |f: fn(f64, f64) -> f64| VectorD::from(x.iter().zip(y.iter()).map(|(&i, &j)| f(i, j)).collect::<Vec<_>>());
+
+    assert_eq!(&x + &y, expected(|i, j| i + j));
+    assert_eq!(x.clone() - &y, expected(|i, j| i - j));
+    assert_eq!(&x * y.clone(), expected(|i, j| i * j));
+    assert_eq!(x.clone() / y.clone(), expected(|i, j| i / j))
+}
+
+#[test]
+fn views() {
+    let mut v = VectorI::from(vec![1, 2, 3, 4, 5, 6]);
+    let w = VectorI::from(vec![10, 20]);
+
+    {
+        let a = v.slice(0..2);
+        let b = v.slice(4..);
+
+        assert_eq!(a.dim(), 2);
+        assert_eq!(a + b, VectorI::from(vec![6, 8]));
+        assert_eq!(b - &w, VectorI::from(vec![-5, -14]));
+        assert_eq!(&w * a, VectorI::from(vec![10, 40]));
+        assert_eq!(a * 3, VectorI::from(vec![3, 6]));
+        assert_eq!(-b.slice(1..), VectorI::from(vec![-6]));
+        assert_eq!(a.dot(&b), 17);
+        assert_eq!(b.sum(), 11);
+        assert_eq!(a.to_vector(), VectorI::from(vec![1, 2]))
+    }
+
+    {
+        let mut m = v.slice_mut(1..3);
+        m += &w;
+        m *= 2;
+        m[0] -= 1;
+    }
+
+    assert_eq!(v, VectorI::from(vec![1, 23, 46, 4, 5, 6]));
+
+    v.slice_mut(3..).fill(0);
+    let u = VectorI::from(vec![1, 1]);
+    let mut x = VectorI::from(vec![1, 2]);
+    x += u.view();
+    assert_eq!(x, VectorI::from(vec![2, 3]));
+    assert_eq!(v.slice(3..).sum(), 0);
+
+    let d = VectorD::from(vec![0.0, 3.0, 4.0]);
+    assert_eq!(d.slice(1..).mag(), 5.0)
+}
+
+#[test]
+fn strided_views() {
+    let mut v = VectorI::from(vec![0, 1, 2, 3, 4, 5, 6, 7]);
+
+    {
+        let even = v.strided(0, 2, 4);
+        let odd = v.strided(1, 2, 4);
+
+        assert_eq!(even.dim(), 4);
+        assert_eq!(odd - even, VectorI::from(vec![1, 1, 1, 1]));
+        assert_eq!(even.dot(&odd), 2 * 3 + 4 * 5 + 6 * 7);
+        assert_eq!(even.slice(1..3).to_vector(), VectorI::from(vec![2, 4]));
+        assert_eq!(even.strided(1, 2, 2).to_vector(), VectorI::from(vec![2, 6]));
+        assert_eq!(odd[3], 7);
+        assert_eq!(odd.sum(), 16)
+    }
+
+    let src = VectorI::from(vec![10, 20, 30, 40]);
+    {
+        let mut even = v.strided_mut(0, 2, 4);
+        even.copy_from(src.view());
+        even += src.view();
+    }
+
+    assert_eq!(v, VectorI::from(vec![20, 1, 40, 3, 60, 5, 80, 7]));
+
+    let bytes = ::VectorU8::from(vec![1, 2, 3]);
+    v.strided_mut(1, 2, 3).assign(bytes.view());
+    assert_eq!(v, VectorI::from(vec![20, 1, 40, 2, 60, 3, 80, 7]));
+
+    // empty ranges at the end of a strided view lie past its last element in the buffer
+    assert_eq!(v.strided(0, 2, 3).slice(3..).dim(), 0);
+    assert_eq!(v.strided(1, 3, 3).strided(3, 2, 0).to_vector(), VectorI::new(0));
+    v.strided_mut(0, 2, 3).slice_mut(3..).fill(9);
+    assert_eq!(v.sum(), 213)
+}
+
+#[test]
+#[should_panic(expected = "view range out of bounds")]
+fn strided_overflow() {
+    VectorI::new(4).strided(1, usize::max_value(), 2);
+}
+
+#[test]
+#[should_panic(expected = "view range out of bounds")]
+fn strided_view_overflow() {
+    VectorI::new(4).view().strided(0, usize::max_value() / 2 + 1, 3);
+}
+
+#[test]
+fn lazy() {
+    let a = VectorD::from(vec![1.0, 2.0, 3.0]);
+    let b = VectorD::from(vec![4.0, 5.0, 6.0]);
+    let c = VectorD::from(vec![0.5, 0.5, 0.5]);
+
+    let expr = a.lazy() * 2.0 + &b - c.slice(..);
+    assert_eq!(expr.dim(), 3);
+    assert_eq!(expr.eval(), &a * 2.0 + &b - &c);
+    assert_eq!(expr.sum(), 25.5);
+
+    let mut out = VectorD::new(3);
+    (&a - -b.lazy()).eval_into(out.view_mut());
+    assert_eq!(out, VectorD::from(vec![5.0, 7.0, 9.0]));
+
+    out -= a.lazy().map(|x| x * x);
+    assert_eq!(out, VectorD::from(vec![4.0, 3.0, 0.0]));
+
+    let v: VectorD = (a.lazy() / 2.0 * b.lazy()).into();
+    assert_eq!(v, VectorD::from(vec![2.0, 5.0, 9.0]))
+}
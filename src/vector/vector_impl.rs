@@ -1,4 +1,5 @@
 use super::{Vector, InVector};
+use summation;
 
 use std::{
     vec::Vec,
@@ -145,6 +146,27 @@ impl<T: InVector> Vector<T>
 
         (y / x).acos()
     }
+
+    /// the magnitude, scaled so that it does not overflow or underflow for extreme components
+    pub fn mag_scaled(&self) -> T {
+        summation::nrm2(self)
+    }
+
+    /// sums up the elements of the vector using compensated summation
+    pub fn sum_kahan(&self) -> T {
+        summation::sum_kahan(self)
+    }
+
+    /// sums up the elements of the vector using pairwise summation
+    pub fn sum_pairwise(&self) -> T {
+        summation::sum_pairwise(self)
+    }
+
+    /// takes the dot product of the two vectors in compensated arithmetic
+    pub fn dot_compensated(&self, other: &Self) -> T {
+        assert_eq!(self.dim(), other.dim());
+        summation::dot_compensated(self, other)
+    }
 }
 
 impl<T: InVector> Vector<T> 
@@ -0,0 +1,115 @@
+use super::Vector;
+#[cfg(feature = "spec_f32_f64")]
+use super::simd;
+
+use std::ops::*;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "parallel")]
+use super::parallel::PARALLEL_THRESHOLD;
+
+// the number of elements each thread hands to a kernel at a time
+#[cfg(feature = "parallel")]
+const PARALLEL_CHUNK: usize = 1 << 12;
+
+// applies `kernel` to `a` and `b`, split across threads once they reach `PARALLEL_THRESHOLD`
+#[cfg(feature = "spec_f32_f64")]
+fn apply<T: Send + Sync>(a: &mut [T], b: &[T], kernel: fn(&mut [T], &[T])) {
+    #[cfg(feature = "parallel")]
+    {
+        if a.len() >= PARALLEL_THRESHOLD {
+            return a.par_chunks_mut(PARALLEL_CHUNK)
+                    .zip(b.par_chunks(PARALLEL_CHUNK))
+                    .for_each(|(a, b)| kernel(a, b))
+        }
+    }
+
+    kernel(a, b)
+}
+
+// like `apply`, for the kernels that write into `b`
+#[cfg(feature = "spec_f32_f64")]
+fn apply_rev<T: Send + Sync>(a: &[T], b: &mut [T], kernel: fn(&[T], &mut [T])) {
+    #[cfg(feature = "parallel")]
+    {
+        if a.len() >= PARALLEL_THRESHOLD {
+            return a.par_chunks(PARALLEL_CHUNK)
+                    .zip(b.par_chunks_mut(PARALLEL_CHUNK))
+                    .for_each(|(a, b)| kernel(a, b))
+        }
+    }
+
+    kernel(a, b)
+}
+
+macro_rules! impl_spec {
+    (op => $Op:ident, $fun:ident, $assign:ident, $rev_assign:ident, $Ty:ty) => {
+        impl $Op<Vector<$Ty>> for Vector<$Ty> {
+            fn $fun(mut self, rhs: Vector<$Ty>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                apply(&mut self, &rhs, simd::$assign);
+                self
+            }
+        }
+
+        impl<'a> $Op<&'a Vector<$Ty>> for Vector<$Ty> {
+            fn $fun(mut self, rhs: &'a Vector<$Ty>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                apply(&mut self, rhs, simd::$assign);
+                self
+            }
+        }
+
+        impl<'a> $Op<Vector<$Ty>> for &'a Vector<$Ty> {
+            fn $fun(self, mut rhs: Vector<$Ty>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                apply_rev(self, &mut rhs, simd::$rev_assign);
+                rhs
+            }
+        }
+
+        impl<'a> $Op<&'a Vector<$Ty>> for &'a Vector<$Ty> {
+            fn $fun(self, rhs: &'a Vector<$Ty>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                let mut out = self.clone();
+                apply(&mut out, rhs, simd::$assign);
+                out
+            }
+        }
+    };
+    (ty => $Ty:ident, $add:ident, $rev_add:ident, $sub:ident, $rev_sub:ident,
+     $mul:ident, $rev_mul:ident, $div:ident, $rev_div:ident, $sum:ident, $dot:ident) => {
+        impl_spec!(op => Add, add, $add, $rev_add, $Ty);
+        impl_spec!(op => Sub, sub, $sub, $rev_sub, $Ty);
+        impl_spec!(op => Mul, mul, $mul, $rev_mul, $Ty);
+        impl_spec!(op => Div, div, $div, $rev_div, $Ty);
+
+        impl Vector<$Ty> {
+            /// sums up the elements with AVX when the cpu supports it, the elements are added in
+            /// one partial sum per SIMD lane so the result can differ from `sum` in the last bits,
+            /// but is the same on every cpu
+            pub fn sum_simd(&self) -> $Ty {
+                simd::$sum(self)
+            }
+
+            /// takes the dot product with AVX when the cpu supports it, added in the same order as `sum_simd`
+            pub fn dot_simd(&self, other: &Vector<$Ty>) -> $Ty {
+                assert_eq!(self.dim(), other.dim());
+                simd::$dot(self, other)
+            }
+
+            /// the squared magnitude with AVX when the cpu supports it, added in the same order as `sum_simd`
+            pub fn magsq_simd(&self) -> $Ty {
+                simd::$dot(self, self)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "spec_f32_f64")]
+impl_spec!(ty => f32, add_assign_f32, rev_add_assign_f32, sub_assign_f32, rev_sub_assign_f32,
+                mul_assign_f32, rev_mul_assign_f32, div_assign_f32, rev_div_assign_f32, sum_f32, dot_f32);
+#[cfg(feature = "spec_f32_f64")]
+impl_spec!(ty => f64, add_assign_f64, rev_add_assign_f64, sub_assign_f64, rev_sub_assign_f64,
+                mul_assign_f64, rev_mul_assign_f64, div_assign_f64, rev_div_assign_f64, sum_f64, dot_f64);
@@ -0,0 +1,483 @@
+use super::{Vector, InVector};
+use super::lazy::Expr;
+
+use std::prelude::v1::*;
+use std::ops::*;
+use std::iter::StepBy;
+use std::slice::{self, SliceIndex};
+
+use num::traits::*;
+
+/// a borrowed, possibly strided, window into a vector, arithmetic on views allocates only the result
+#[derive(Debug)]
+pub struct VectorView<'a, T: InVector + 'a> {
+    // spans exactly from the first to the last viewed element
+    data: &'a [T],
+    stride: usize
+}
+
+/// a mutably borrowed, possibly strided, window into a vector, assignment operators write through to the parent
+#[derive(Debug)]
+pub struct VectorViewMut<'a, T: InVector + 'a> {
+    data: &'a mut [T],
+    stride: usize
+}
+
+impl<'a, T> !InVector for VectorView<'a, T> {}
+impl<'a, T> !InVector for VectorViewMut<'a, T> {}
+
+// derive would require `T: Clone`, but a view is just a reference
+impl<'a, T: InVector> Clone for VectorView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: InVector> Copy for VectorView<'a, T> {}
+
+// the range of the underlying buffer covered by `len` elements `stride` apart, an empty view
+// covers nothing so its offset may lie past the end of a strided parent
+fn strided_span(start: usize, stride: usize, len: usize) -> Range<usize> {
+    assert!(stride > 0, "stride must be positive");
+    if len == 0 {
+        0..0
+    } else {
+        let end = last_offset(start, stride, len).and_then(|last| last.checked_add(1));
+        start..end.expect("view range out of bounds")
+    }
+}
+
+// the offset of the last of `len > 0` elements `stride` apart, `None` if it overflows
+fn last_offset(start: usize, stride: usize, len: usize) -> Option<usize> {
+    (len - 1).checked_mul(stride).and_then(|offset| offset.checked_add(start))
+}
+
+// resolves `range` against a view of `dim` elements
+fn bounds<R: RangeBounds<usize>>(range: R, dim: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&i) => i,
+        Bound::Excluded(&i) => i.checked_add(1).expect("view range out of bounds"),
+        Bound::Unbounded => 0
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&i) => i.checked_add(1).expect("view range out of bounds"),
+        Bound::Excluded(&i) => i,
+        Bound::Unbounded => dim
+    };
+
+    assert!(start <= end && end <= dim, "view range out of bounds");
+    (start, end - start)
+}
+
+impl<T: InVector> Vector<T> {
+    /// borrows the whole vector as a view
+    pub fn view(&self) -> VectorView<'_, T> {
+        VectorView { data: &self.0, stride: 1 }
+    }
+
+    /// mutably borrows the whole vector as a view
+    pub fn view_mut(&mut self) -> VectorViewMut<'_, T> {
+        VectorViewMut { data: &mut self.0, stride: 1 }
+    }
+
+    /// borrows the elements in `range` as a view, panics if the range is out of bounds
+    pub fn slice<R>(&self, range: R) -> VectorView<'_, T>
+        where R: SliceIndex<[T], Output = [T]> {
+        VectorView { data: &self.0[range], stride: 1 }
+    }
+
+    /// mutably borrows the elements in `range` as a view, panics if the range is out of bounds
+    pub fn slice_mut<R>(&mut self, range: R) -> VectorViewMut<'_, T>
+        where R: SliceIndex<[T], Output = [T]> {
+        VectorViewMut { data: &mut self.0[range], stride: 1 }
+    }
+
+    /// borrows `len` elements, `stride` apart, beginning at `start` as a view
+    pub fn strided(&self, start: usize, stride: usize, len: usize) -> VectorView<'_, T> {
+        VectorView::strided_from(&self.0, start, stride, len)
+    }
+
+    /// mutably borrows `len` elements, `stride` apart, beginning at `start` as a view
+    pub fn strided_mut(&mut self, start: usize, stride: usize, len: usize) -> VectorViewMut<'_, T> {
+        VectorViewMut::strided_from(&mut self.0, start, stride, len)
+    }
+}
+
+impl<'a, T: InVector> VectorView<'a, T> {
+    /// views `len` elements of `data`, `stride` apart, beginning at `start`
+    pub fn strided_from(data: &'a [T], start: usize, stride: usize, len: usize) -> Self {
+        VectorView { data: &data[strided_span(start, stride, len)], stride }
+    }
+
+    /// the number of elements in the view
+    pub fn dim(&self) -> usize {
+        if self.data.is_empty() { 0 } else { (self.data.len() - 1) / self.stride + 1 }
+    }
+
+    /// the distance between consecutive elements in the underlying buffer
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    pub fn iter(&self) -> StepBy<slice::Iter<'a, T>> {
+        self.data.iter().step_by(self.stride)
+    }
+
+    /// gets value at index, and clones it. This is unnecessary if `T` is `Copy`.
+    pub fn get(&self, index: usize) -> T
+    where T: Clone {
+        self[index].clone()
+    }
+
+    /// a narrower view into the same elements, `range` is relative to this view
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> VectorView<'a, T> {
+        let (start, len) = bounds(range, self.dim());
+        self.strided(start, 1, len)
+    }
+
+    /// every `stride`th element of this view beginning at `start`, relative to this view
+    pub fn strided(&self, start: usize, stride: usize, len: usize) -> VectorView<'a, T> {
+        assert!(len == 0 || last_offset(start, stride, len).map_or(false, |last| last < self.dim()), "view range out of bounds");
+        // only overflows when `len` is too small for the product to matter
+        VectorView::strided_from(self.data, start.saturating_mul(self.stride), stride.saturating_mul(self.stride), len)
+    }
+
+    /// copies the viewed elements into a new vector
+    pub fn to_vector(&self) -> Vector<T>
+    where T: Clone {
+        Vector(self.iter().cloned().collect())
+    }
+
+    /// maps the view's component's according to the function provided
+    pub fn map<U: InVector, F>(&self, f: F) -> Vector<U>
+        where F: Fn(&T) -> U {
+        Vector(self.iter().map(f).collect())
+    }
+
+    /// takes the dot product of the two views
+    pub fn dot<U, O>(&self, other: &VectorView<U>) -> O
+    where U: InVector + Clone,
+          O: InVector + Zero,
+          T: Clone + Mul<U, Output = O> {
+        assert_eq!(self.dim(), other.dim());
+        self.iter()
+            .zip(other.iter())
+            .fold(O::zero(), |acc, (i, j)| acc + i.clone() * j.clone())
+    }
+
+    /// sums up the elements of the view
+    pub fn sum(&self) -> T
+    where T: Zero + Clone {
+        self.iter().fold(T::zero(), |acc, x| acc + x.clone())
+    }
+
+    /// multiplies up the elements of the view
+    pub fn product(&self) -> T
+    where T: One + Clone {
+        self.iter().fold(T::one(), |acc, x| acc * x.clone())
+    }
+
+    /// the square of the magnitude
+    pub fn magsq(&self) -> T
+    where T: Zero + Clone + Mul<Output = T> {
+        self.dot(self)
+    }
+
+    /// the magnitude
+    pub fn mag(&self) -> T
+    where T: Float {
+        self.magsq().sqrt()
+    }
+
+    /// returns a unit vector with the same direction as the view
+    pub fn norm(&self) -> Vector<T>
+    where T: Float {
+        *self / self.mag()
+    }
+}
+
+impl<'a, T: InVector> VectorViewMut<'a, T> {
+    /// mutably views `len` elements of `data`, `stride` apart, beginning at `start`
+    pub fn strided_from(data: &'a mut [T], start: usize, stride: usize, len: usize) -> Self {
+        VectorViewMut { data: &mut data[strided_span(start, stride, len)], stride }
+    }
+
+    /// the number of elements in the view
+    pub fn dim(&self) -> usize {
+        self.view().dim()
+    }
+
+    /// the distance between consecutive elements in the underlying buffer
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// reborrows as an immutable view, giving access to the arithmetic and reductions
+    pub fn view(&self) -> VectorView<'_, T> {
+        VectorView { data: &*self.data, stride: self.stride }
+    }
+
+    pub fn iter(&self) -> StepBy<slice::Iter<'_, T>> {
+        self.data.iter().step_by(self.stride)
+    }
+
+    pub fn iter_mut(&mut self) -> StepBy<slice::IterMut<'_, T>> {
+        self.data.iter_mut().step_by(self.stride)
+    }
+
+    /// a narrower mutable view into the same elements, `range` is relative to this view
+    pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> VectorViewMut<'_, T> {
+        let (start, len) = bounds(range, self.dim());
+        self.strided_mut(start, 1, len)
+    }
+
+    /// every `stride`th element of this view beginning at `start`, relative to this view
+    pub fn strided_mut(&mut self, start: usize, stride: usize, len: usize) -> VectorViewMut<'_, T> {
+        assert!(len == 0 || last_offset(start, stride, len).map_or(false, |last| last < self.dim()), "view range out of bounds");
+        let outer = self.stride;
+        VectorViewMut::strided_from(&mut *self.data, start.saturating_mul(outer), stride.saturating_mul(outer), len)
+    }
+
+    /// sets every element to `value`
+    pub fn fill(&mut self, value: T)
+    where T: Clone {
+        self.iter_mut().for_each(|i| *i = value.clone())
+    }
+
+    /// overwrites the viewed elements with those of `src`
+    pub fn copy_from(&mut self, src: VectorView<T>)
+    where T: Clone {
+        assert_eq!(self.dim(), src.dim());
+        self.iter_mut()
+            .zip(src.iter())
+            .for_each(|(i, j)| *i = j.clone())
+    }
+
+    /// overwrites the viewed elements with those of `src`, converting them with `Into`
+    pub fn assign<U: InVector + Clone + Into<T>>(&mut self, src: VectorView<U>) {
+        assert_eq!(self.dim(), src.dim());
+        self.iter_mut()
+            .zip(src.iter())
+            .for_each(|(i, j)| *i = j.clone().into())
+    }
+}
+
+impl<'a, 'b, T: InVector + PartialEq> PartialEq<VectorView<'b, T>> for VectorView<'a, T> {
+    fn eq(&self, other: &VectorView<'b, T>) -> bool {
+        self.dim() == other.dim() && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, 'b, T: InVector + PartialEq> PartialEq<VectorViewMut<'b, T>> for VectorViewMut<'a, T> {
+    fn eq(&self, other: &VectorViewMut<'b, T>) -> bool {
+        self.view() == other.view()
+    }
+}
+
+impl<'a, T: InVector> Index<usize> for VectorView<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        assert!(index < self.dim(), "index out of bounds");
+        &self.data[index * self.stride]
+    }
+}
+
+// the dimensions of a lazy expression are checked as it is built, so elements are read straight
+// from the buffer without the assert in `Index`
+impl<'a, T: InVector + Clone> Expr for VectorView<'a, T> {
+    type Item = T;
+
+    fn dim(&self) -> usize {
+        VectorView::dim(self)
+    }
+
+    fn at(&self, index: usize) -> T {
+        self.data[index * self.stride].clone()
+    }
+}
+
+impl<'a, T: InVector> Index<usize> for VectorViewMut<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        assert!(index < self.dim(), "index out of bounds");
+        &self.data[index * self.stride]
+    }
+}
+
+impl<'a, T: InVector> IndexMut<usize> for VectorViewMut<'a, T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        assert!(index < self.dim(), "index out of bounds");
+        &mut self.data[index * self.stride]
+    }
+}
+
+impl<'a, T: InVector> IntoIterator for VectorView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = StepBy<slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter().step_by(self.stride)
+    }
+}
+
+impl<'a, T: InVector> IntoIterator for VectorViewMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = StepBy<slice::IterMut<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut().step_by(self.stride)
+    }
+}
+
+macro_rules! impl_view_op {
+    (view, view => $Op:ident, $func:ident) => {
+        impl<'a, 'b, T: Clone, U: Clone, O> $Op<VectorView<'b, U>> for VectorView<'a, T>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector {
+            type Output = Vector<O>;
+
+            fn $func(self, rhs: VectorView<'b, U>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                Vector(self.iter()
+                           .zip(rhs.iter())
+                           .map(|(i, j)| i.clone().$func(j.clone()))
+                           .collect())
+            }
+        }
+    };
+    (view, borrow => $Op:ident, $func:ident) => {
+        impl<'a, 'b, T: Clone, U: Clone, O> $Op<&'b Vector<U>> for VectorView<'a, T>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector {
+            type Output = Vector<O>;
+
+            fn $func(self, rhs: &'b Vector<U>) -> Self::Output {
+                self.$func(rhs.view())
+            }
+        }
+    };
+    (borrow, view => $Op:ident, $func:ident) => {
+        impl<'a, 'b, T: Clone, U: Clone, O> $Op<VectorView<'b, U>> for &'a Vector<T>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector {
+            type Output = Vector<O>;
+
+            fn $func(self, rhs: VectorView<'b, U>) -> Self::Output {
+                self.view().$func(rhs)
+            }
+        }
+    };
+    (own, view => $Op:ident, $func:ident) => {
+        impl<'b, T, U: Clone, O> $Op<VectorView<'b, U>> for Vector<T>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector {
+            type Output = Vector<O>;
+
+            fn $func(self, rhs: VectorView<'b, U>) -> Self::Output {
+                assert_eq!(self.dim(), rhs.dim());
+                Vector(self.into_iter()
+                           .zip(rhs.iter())
+                           .map(|(i, j)| i.$func(j.clone()))
+                           .collect())
+            }
+        }
+    };
+    (scalar => $Op:ident, $func:ident) => {
+        impl<'a, T: Clone, U: Clone, O> $Op<U> for VectorView<'a, T>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector {
+            type Output = Vector<O>;
+
+            fn $func(self, rhs: U) -> Self::Output {
+                self.map(|i| i.clone().$func(rhs.clone()))
+            }
+        }
+    };
+    (assign, view_mut, view => $Op:ident, $func:ident) => {
+        impl<'a, 'b, T, U: Clone> $Op<VectorView<'b, U>> for VectorViewMut<'a, T>
+            where T: InVector + $Op<U>,
+                  U: InVector {
+            fn $func(&mut self, rhs: VectorView<'b, U>) {
+                assert_eq!(self.dim(), rhs.dim());
+                self.iter_mut()
+                    .zip(rhs.iter())
+                    .for_each(|(i, j)| i.$func(j.clone()))
+            }
+        }
+    };
+    (assign, view_mut, borrow => $Op:ident, $func:ident) => {
+        impl<'a, 'b, T, U: Clone> $Op<&'b Vector<U>> for VectorViewMut<'a, T>
+            where T: InVector + $Op<U>,
+                  U: InVector {
+            fn $func(&mut self, rhs: &'b Vector<U>) {
+                self.$func(rhs.view())
+            }
+        }
+    };
+    (assign, own, view => $Op:ident, $func:ident) => {
+        impl<'b, T, U: Clone> $Op<VectorView<'b, U>> for Vector<T>
+            where T: InVector + $Op<U>,
+                  U: InVector {
+            fn $func(&mut self, rhs: VectorView<'b, U>) {
+                self.view_mut().$func(rhs)
+            }
+        }
+    };
+    (assign, scalar => $Op:ident, $func:ident) => {
+        impl<'a, T, U: Clone> $Op<U> for VectorViewMut<'a, T>
+            where T: InVector + $Op<U>,
+                  U: InVector {
+            fn $func(&mut self, rhs: U) {
+                self.iter_mut().for_each(|i| i.$func(rhs.clone()))
+            }
+        }
+    };
+    (all => $($Op:ident, $func:ident, $OpAssign:ident, $func_assign:ident;)*) => {$(
+        impl_view_op!(view, view => $Op, $func);
+        impl_view_op!(view, borrow => $Op, $func);
+        impl_view_op!(borrow, view => $Op, $func);
+        impl_view_op!(own, view => $Op, $func);
+
+        impl_view_op!(assign, view_mut, view => $OpAssign, $func_assign);
+        impl_view_op!(assign, view_mut, borrow => $OpAssign, $func_assign);
+        impl_view_op!(assign, own, view => $OpAssign, $func_assign);
+    )*};
+}
+
+impl_view_op! { all =>
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign;
+    BitAnd, bitand, BitAndAssign, bitand_assign;
+    BitOr, bitor, BitOrAssign, bitor_assign;
+    BitXor, bitxor, BitXorAssign, bitxor_assign;
+    Shl, shl, ShlAssign, shl_assign;
+    Shr, shr, ShrAssign, shr_assign;
+}
+
+impl_view_op!(scalar => Mul, mul);
+impl_view_op!(scalar => Div, div);
+impl_view_op!(assign, scalar => MulAssign, mul_assign);
+impl_view_op!(assign, scalar => DivAssign, div_assign);
+
+impl<'a, T: InVector + Clone, O: InVector> Neg for VectorView<'a, T>
+where T: Neg<Output = O> {
+    type Output = Vector<O>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|x| -x.clone())
+    }
+}
+
+impl<'a, T: InVector + Clone, O: InVector> Not for VectorView<'a, T>
+where T: Not<Output = O> {
+    type Output = Vector<O>;
+
+    fn not(self) -> Self::Output {
+        self.map(|x| !x.clone())
+    }
+}
@@ -0,0 +1,93 @@
+use super::{Vector, Matrix, InVector, NotPositiveDefiniteError};
+
+#[cfg(feature = "no_std")]
+use core::fmt;
+#[cfg(not(feature = "no_std"))]
+use std::fmt;
+
+use num::traits::*;
+
+use super::generic_array::{GenericArray, ArrayLength};
+
+/// the Cholesky factorization of a symmetric positive definite matrix, `A = L * L^T`
+#[derive(Clone, PartialEq, Debug)]
+pub struct Cholesky<T, N>
+    where T: InVector,
+          N: ArrayLength<GenericArray<T, N>> + ArrayLength<T> {
+    l: Matrix<T, N, N>
+}
+
+impl<T: InVector, N> Matrix<T, N, N>
+    where T: Float,
+          N: ArrayLength<GenericArray<T, N>> + ArrayLength<T> {
+    /// factorizes the matrix into `A = L * L^T`, only the lower triangle of the matrix is read
+    pub fn cholesky(&self) -> Result<Cholesky<T, N>, NotPositiveDefiniteError> {
+        let n = N::to_usize();
+        let mut l = Matrix::<T, N, N>::new();
+
+        for j in 0..n {
+            let d = (0..j).fold(self[(j, j)], |acc, k| acc - l[(j, k)] * l[(j, k)]);
+
+            // written so that NaN is rejected too
+            if !(d > T::zero()) {
+                return Err(NotPositiveDefiniteError)
+            }
+
+            let d = d.sqrt();
+            l[(j, j)] = d;
+
+            for i in j + 1..n {
+                let s = (0..j).fold(self[(i, j)], |acc, k| acc - l[(i, k)] * l[(j, k)]);
+                l[(i, j)] = s / d;
+            }
+        }
+
+        Ok(Cholesky { l })
+    }
+}
+
+impl<T: InVector, N> Cholesky<T, N>
+    where T: Float,
+          N: ArrayLength<GenericArray<T, N>> + ArrayLength<T> {
+    /// the lower triangular factor
+    pub fn l(&self) -> Matrix<T, N, N> {
+        self.l.clone()
+    }
+
+    /// solves `A * x = b` for `x`
+    pub fn solve(&self, b: &Vector<T, N>) -> Vector<T, N> {
+        let n = N::to_usize();
+        let mut x = b.clone();
+
+        // forward substitution with L
+        for i in 0..n {
+            for j in 0..i {
+                x[i] = x[i] - self.l[(i, j)] * x[j];
+            }
+            x[i] = x[i] / self.l[(i, i)];
+        }
+
+        // back substitution with L^T
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] = x[i] - self.l[(j, i)] * x[j];
+            }
+            x[i] = x[i] / self.l[(i, i)];
+        }
+
+        x
+    }
+
+    /// the natural log of the determinant of the factorized matrix
+    pub fn log_det(&self) -> T {
+        let sum = (0..N::to_usize()).fold(T::zero(), |acc, i| acc + self.l[(i, i)].ln());
+
+        sum + sum
+    }
+}
+
+impl fmt::Display for NotPositiveDefiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Not Positive Definite")
+    }
+}
@@ -0,0 +1,46 @@
+use super::{Vector, InVector};
+use coords::{Polar, Spherical, Cylindrical};
+
+use num::traits::Float;
+
+use super::typenum::{U2, U3};
+
+impl<T: InVector + Float> Vector<T, U2> {
+    /// converts to polar coordinates
+    pub fn to_polar(&self) -> Polar<T> {
+        Polar::from_cartesian(self[0], self[1])
+    }
+
+    /// creates a vector from polar coordinates
+    pub fn from_polar(polar: &Polar<T>) -> Self {
+        let (x, y) = polar.to_cartesian();
+
+        Vector::<T, U2>::create(x, y)
+    }
+}
+
+impl<T: InVector + Float> Vector<T, U3> {
+    /// converts to spherical coordinates
+    pub fn to_spherical(&self) -> Spherical<T> {
+        Spherical::from_cartesian(self[0], self[1], self[2])
+    }
+
+    /// creates a vector from spherical coordinates
+    pub fn from_spherical(spherical: &Spherical<T>) -> Self {
+        let (x, y, z) = spherical.to_cartesian();
+
+        Vector::<T, U3>::create(x, y, z)
+    }
+
+    /// converts to cylindrical coordinates
+    pub fn to_cylindrical(&self) -> Cylindrical<T> {
+        Cylindrical::from_cartesian(self[0], self[1], self[2])
+    }
+
+    /// creates a vector from cylindrical coordinates
+    pub fn from_cylindrical(cylindrical: &Cylindrical<T>) -> Self {
+        let (x, y, z) = cylindrical.to_cartesian();
+
+        Vector::<T, U3>::create(x, y, z)
+    }
+}
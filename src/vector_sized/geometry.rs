@@ -0,0 +1,88 @@
+use super::{Vector, InVector};
+
+#[cfg(feature = "no_std")]
+use core::ops::{Add, Sub, Mul};
+#[cfg(not(feature = "no_std"))]
+use std::ops::{Add, Sub, Mul};
+
+use num::traits::Zero;
+
+use super::typenum::{U2, U3};
+
+/// the cross product, only defined for 3 dimensional vectors
+pub trait Cross<Rhs = Self> {
+    type Output;
+
+    fn cross(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// the perp dot product of 2 dimensional vectors, the z component of their cross product
+pub trait PerpDot<Rhs = Self> {
+    type Output;
+
+    fn perp_dot(&self, rhs: &Rhs) -> Self::Output;
+}
+
+impl<T: InVector + Clone> Cross for Vector<T, U3>
+where T: Mul<Output = T> + Sub<Output = T> {
+    type Output = Vector<T, U3>;
+
+    default fn cross(&self, rhs: &Self) -> Self::Output {
+        let (a, b) = (&self.0, &rhs.0);
+
+        Vector::<_, U3>::create(
+            a[1].clone() * b[2].clone() - a[2].clone() * b[1].clone(),
+            a[2].clone() * b[0].clone() - a[0].clone() * b[2].clone(),
+            a[0].clone() * b[1].clone() - a[1].clone() * b[0].clone()
+        )
+    }
+}
+
+impl<T: InVector + Clone> PerpDot for Vector<T, U2>
+where T: Mul<Output = T> + Sub<Output = T> {
+    type Output = T;
+
+    default fn perp_dot(&self, rhs: &Self) -> Self::Output {
+        let (a, b) = (&self.0, &rhs.0);
+
+        a[0].clone() * b[1].clone() - a[1].clone() * b[0].clone()
+    }
+}
+
+impl<T: InVector + Clone> Vector<T, U3>
+where T: Zero + Mul<Output = T> + Sub<Output = T> + Add<Output = T> {
+    /// the scalar triple product `self . (b x c)`, the signed volume of the parallelepiped spanned by the vectors
+    pub fn scalar_triple(&self, b: &Self, c: &Self) -> T {
+        self.dot(&b.cross(c))
+    }
+
+    /// the vector triple product `self x (b x c)`
+    pub fn vector_triple(&self, b: &Self, c: &Self) -> Self {
+        self.cross(&b.cross(c))
+    }
+}
+
+macro_rules! impl_spec {
+    ($($Ty:ty),*) => {$(
+        impl Cross for Vector<$Ty, U3> {
+            fn cross(&self, rhs: &Self) -> Self::Output {
+                let (a, b) = (&self.0, &rhs.0);
+
+                Vector::<_, U3>::create(
+                    a[1] * b[2] - a[2] * b[1],
+                    a[2] * b[0] - a[0] * b[2],
+                    a[0] * b[1] - a[1] * b[0]
+                )
+            }
+        }
+
+        impl PerpDot for Vector<$Ty, U2> {
+            fn perp_dot(&self, rhs: &Self) -> Self::Output {
+                self.0[0] * rhs.0[1] - self.0[1] * rhs.0[0]
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "spec_f32_f64")]
+impl_spec!(f32, f64);
@@ -0,0 +1,286 @@
+use super::{Vector, Matrix, InVector, TryFromVectorError, SingularMatrixError};
+
+#[cfg(feature = "no_std")]
+use core::{
+    ops::{Mul, Deref, DerefMut, Index, IndexMut},
+    convert::{TryFrom, Into},
+    cmp::Ordering,
+    fmt
+};
+#[cfg(not(feature = "no_std"))]
+use std::{
+    vec::Vec,
+    ops::{Mul, Deref, DerefMut, Index, IndexMut},
+    convert::{TryFrom, Into},
+    cmp::Ordering,
+    fmt
+};
+
+use num::traits::*;
+
+use super::generic_array::{GenericArray, ArrayLength};
+use serde::{Deserialize, Deserializer};
+
+impl<T: InVector, R, C> Matrix<T, R, C>
+    where R: ArrayLength<GenericArray<T, C>>,
+          C: ArrayLength<T> {
+    /// creates a matrix of 0.0s
+    pub fn new() -> Self
+    where T: Zero {
+        Matrix((0..R::to_usize()).map(|_| {
+            (0..C::to_usize()).map(|_| T::zero()).collect()
+        }).collect())
+    }
+
+    /// creates a matrix whose rows are the given vectors
+    pub fn from_rows(rows: GenericArray<Vector<T, C>, R>) -> Self
+    where R: ArrayLength<Vector<T, C>> {
+        Matrix(rows.into_iter().map(|row| row.0).collect())
+    }
+
+    /// the number of rows in the matrix
+    pub fn rows(&self) -> usize {
+        R::to_usize()
+    }
+
+    /// the number of columns in the matrix
+    pub fn cols(&self) -> usize {
+        C::to_usize()
+    }
+
+    /// the shape of the matrix as `(rows, cols)`
+    pub fn dim(&self) -> (usize, usize) {
+        (R::to_usize(), C::to_usize())
+    }
+
+    /// gets value at `(row, col)`, and clones it. This is unnecessary if `T` is `Copy`.
+    pub fn get(&self, row: usize, col: usize) -> T
+    where T: Clone {
+        self[(row, col)].clone()
+    }
+
+    /// copies a row of the matrix into a vector
+    pub fn row(&self, row: usize) -> Vector<T, C>
+    where T: Clone {
+        Vector(self.0[row].clone())
+    }
+
+    /// copies a column of the matrix into a vector
+    pub fn col(&self, col: usize) -> Vector<T, R>
+    where T: Clone,
+          R: ArrayLength<T> {
+        Vector(self.0.iter().map(|row| row[col].clone()).collect())
+    }
+
+    /// swaps two rows of the matrix
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        self.0.swap(a, b)
+    }
+
+    /// gets the transpose of the matrix
+    pub fn transpose(&self) -> Matrix<T, C, R>
+    where T: Clone,
+          C: ArrayLength<GenericArray<T, R>>,
+          R: ArrayLength<T> {
+        Matrix((0..C::to_usize()).map(|c| {
+            self.0.iter().map(|row| row[c].clone()).collect()
+        }).collect())
+    }
+
+    /// the element-wise product of two matrices of the same shape
+    pub fn hadamard<U, O>(&self, other: &Matrix<U, R, C>) -> Matrix<O, R, C>
+        where U: InVector + Clone,
+              O: InVector,
+              T: Clone + Mul<U, Output = O>,
+              R: ArrayLength<GenericArray<U, C>> + ArrayLength<GenericArray<O, C>>,
+              C: ArrayLength<U> + ArrayLength<O> {
+        Matrix(self.0.iter()
+                     .zip(other.0.iter())
+                     .map(|(a, b)| a.iter()
+                                    .zip(b.iter())
+                                    .map(|(i, j)| i.clone() * j.clone())
+                                    .collect())
+                     .collect())
+    }
+
+    /// conversion functions between different matrix types (if the type implements from)
+    pub fn into<U>(self) -> Matrix<U, R, C>
+        where U: InVector,
+              T: Into<U>,
+              R: ArrayLength<GenericArray<U, C>>,
+              C: ArrayLength<U> {
+        self.map(|x| x.into())
+    }
+
+    /// maps the matrix's component's according to the function provided
+    pub fn map<U: InVector, F>(self, f: F) -> Matrix<U, R, C>
+        where F: Fn(T) -> U,
+              R: ArrayLength<GenericArray<U, C>>,
+              C: ArrayLength<U> {
+        Matrix(self.0.into_iter().map(|row| row.into_iter().map(&f).collect()).collect())
+    }
+
+    /// maps the matrix's component's according to the function provided
+    pub fn map_ref<U: InVector, F>(&self, f: F) -> Matrix<U, R, C>
+        where F: Fn(&T) -> U,
+              R: ArrayLength<GenericArray<U, C>>,
+              C: ArrayLength<U> {
+        Matrix(self.0.iter().map(|row| row.iter().map(&f).collect()).collect())
+    }
+
+    /// iterates over the elements in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.0.iter().flat_map(|row| row.iter())
+    }
+
+    /// iterates mutably over the elements in row-major order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.0.iter_mut().flat_map(|row| row.iter_mut())
+    }
+}
+
+impl<T: InVector, N> Matrix<T, N, N>
+    where N: ArrayLength<GenericArray<T, N>> + ArrayLength<T> {
+    /// creates the identity matrix
+    pub fn identity() -> Self
+    where T: Zero + One {
+        let mut mat = Self::new();
+
+        for i in 0..N::to_usize() {
+            mat[(i, i)] = T::one();
+        }
+
+        mat
+    }
+
+    /// the inverse of the matrix using Gauss-Jordan elimination, or an error if it is singular
+    pub fn inverse(&self) -> Result<Self, SingularMatrixError>
+    where T: Float {
+        let n = N::to_usize();
+        let mut a = self.clone();
+        let mut inv = Self::identity();
+
+        for k in 0..n {
+            let pivot_row = (k..n).max_by(|&i, &j| {
+                a[(i, k)].abs().partial_cmp(&a[(j, k)].abs()).unwrap_or(Ordering::Equal)
+            }).unwrap();
+
+            if a[(pivot_row, k)].is_zero() {
+                return Err(SingularMatrixError)
+            }
+
+            a.swap_rows(pivot_row, k);
+            inv.swap_rows(pivot_row, k);
+
+            let pivot = a[(k, k)];
+
+            for j in 0..n {
+                a[(k, j)] = a[(k, j)] / pivot;
+                inv[(k, j)] = inv[(k, j)] / pivot;
+            }
+
+            for i in (0..n).filter(|&i| i != k) {
+                let factor = a[(i, k)];
+
+                for j in 0..n {
+                    a[(i, j)] = a[(i, j)] - factor * a[(k, j)];
+                    inv[(i, j)] = inv[(i, j)] - factor * inv[(k, j)];
+                }
+            }
+        }
+
+        Ok(inv)
+    }
+}
+
+// traits
+impl<T: InVector, R, C> From<GenericArray<GenericArray<T, C>, R>> for Matrix<T, R, C>
+    where R: ArrayLength<GenericArray<T, C>>,
+          C: ArrayLength<T> {
+    fn from(value: GenericArray<GenericArray<T, C>, R>) -> Self {
+        Matrix(value)
+    }
+}
+
+impl<'a, T: InVector + Clone, R, C> TryFrom<&'a [T]> for Matrix<T, R, C>
+    where R: ArrayLength<GenericArray<T, C>>,
+          C: ArrayLength<T> {
+    type Error = TryFromVectorError;
+
+    // get a matrix from a row-major slice
+    fn try_from(value: &'a [T]) -> Result<Self, Self::Error> {
+        let cols = C::to_usize();
+
+        if value.len() == R::to_usize() * cols {
+            Ok(Matrix((0..R::to_usize()).map(|r| {
+                GenericArray::clone_from_slice(&value[r * cols..(r + 1) * cols])
+            }).collect()))
+        } else {
+            Err(TryFromVectorError)
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<T: InVector + Clone, R, C> TryFrom<Vec<T>> for Matrix<T, R, C>
+    where R: ArrayLength<GenericArray<T, C>>,
+          C: ArrayLength<T> {
+    type Error = TryFromVectorError;
+
+    // get a matrix from a row-major vec
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        Self::try_from(&value as &[T])
+    }
+}
+
+impl<T: InVector, R, C> Index<(usize, usize)> for Matrix<T, R, C>
+    where R: ArrayLength<GenericArray<T, C>>,
+          C: ArrayLength<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.0[row][col]
+    }
+}
+
+impl<T: InVector, R, C> IndexMut<(usize, usize)> for Matrix<T, R, C>
+    where R: ArrayLength<GenericArray<T, C>>,
+          C: ArrayLength<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.0[row][col]
+    }
+}
+
+impl<T: InVector, R, C> Deref for Matrix<T, R, C>
+    where R: ArrayLength<GenericArray<T, C>>,
+          C: ArrayLength<T> {
+    type Target = GenericArray<GenericArray<T, C>, R>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: InVector, R, C> DerefMut for Matrix<T, R, C>
+    where R: ArrayLength<GenericArray<T, C>>,
+          C: ArrayLength<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<'de, T: InVector + Default + Deserialize<'de>, R, C> Deserialize<'de> for Matrix<T, R, C>
+    where R: ArrayLength<GenericArray<T, C>>,
+          C: ArrayLength<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        let arr = <GenericArray<GenericArray<T, C>, R> as Deserialize<'de>>::deserialize(deserializer)?;
+        Ok(Matrix(arr))
+    }
+}
+
+impl fmt::Display for SingularMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Singular Matrix")
+    }
+}
@@ -0,0 +1,339 @@
+#[cfg(feature = "no_std")]
+use core::ops::*;
+#[cfg(not(feature = "no_std"))]
+use std::ops::*;
+
+use super::{Vector, Matrix, InVector};
+use super::generic_array::{GenericArray, ArrayLength};
+
+use num::traits::Zero;
+
+macro_rules! impl_op {
+    (own => $Op:ident, $func:ident, $op:tt) => {
+        impl<R, C, T, U: Clone, O> $Op<U> for Matrix<T, R, C>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector,
+                  R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<O, C>>,
+                  C: ArrayLength<T> + ArrayLength<O> {
+            type Output = Matrix<O, R, C>;
+
+            default fn $func(self, rhs: U) -> Self::Output {
+                self.map(|i| i.$func(rhs.clone()))
+            }
+        }
+    };
+    (borrow => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, R, C, T: Clone, U: Clone, O> $Op<U> for &'a Matrix<T, R, C>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector,
+                  R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<O, C>>,
+                  C: ArrayLength<T> + ArrayLength<O> {
+            type Output = Matrix<O, R, C>;
+
+            default fn $func(self, rhs: U) -> Self::Output {
+                self.map_ref(|i| i.clone().$func(rhs.clone()))
+            }
+        }
+    };
+    (own, own => $Op:ident, $func:ident, $op:tt) => {
+        impl<R, C, T, U, O> $Op<Matrix<U, R, C>> for Matrix<T, R, C>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector,
+                  R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<U, C>> + ArrayLength<GenericArray<O, C>>,
+                  C: ArrayLength<T> + ArrayLength<U> + ArrayLength<O> {
+            type Output = Matrix<O, R, C>;
+
+            default fn $func(self, rhs: Matrix<U, R, C>) -> Self::Output {
+                Matrix(self.0.into_iter()
+                             .zip(rhs.0.into_iter())
+                             .map(|(a, b)| a.into_iter()
+                                            .zip(b.into_iter())
+                                            .map(|(i, j)| i.$func(j))
+                                            .collect())
+                             .collect())
+            }
+        }
+    };
+    (own, borrow => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, R, C, T, U: Clone, O> $Op<&'a Matrix<U, R, C>> for Matrix<T, R, C>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector,
+                  R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<U, C>> + ArrayLength<GenericArray<O, C>>,
+                  C: ArrayLength<T> + ArrayLength<U> + ArrayLength<O> {
+            type Output = Matrix<O, R, C>;
+
+            default fn $func(self, rhs: &'a Matrix<U, R, C>) -> Self::Output {
+                Matrix(self.0.into_iter()
+                             .zip(rhs.0.iter())
+                             .map(|(a, b)| a.into_iter()
+                                            .zip(b.iter())
+                                            .map(|(i, j)| i.$func(j.clone()))
+                                            .collect())
+                             .collect())
+            }
+        }
+    };
+    (borrow, own => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, R, C, T: Clone, U, O> $Op<Matrix<U, R, C>> for &'a Matrix<T, R, C>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector,
+                  R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<U, C>> + ArrayLength<GenericArray<O, C>>,
+                  C: ArrayLength<T> + ArrayLength<U> + ArrayLength<O> {
+            type Output = Matrix<O, R, C>;
+
+            default fn $func(self, rhs: Matrix<U, R, C>) -> Self::Output {
+                Matrix(self.0.iter()
+                             .zip(rhs.0.into_iter())
+                             .map(|(a, b)| a.iter()
+                                            .zip(b.into_iter())
+                                            .map(|(i, j)| i.clone().$func(j))
+                                            .collect())
+                             .collect())
+            }
+        }
+    };
+    (borrow, borrow => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, R, C, T: Clone, U: Clone, O> $Op<&'a Matrix<U, R, C>> for &'a Matrix<T, R, C>
+            where T: InVector + $Op<U, Output = O>,
+                  U: InVector, O: InVector,
+                  R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<U, C>> + ArrayLength<GenericArray<O, C>>,
+                  C: ArrayLength<T> + ArrayLength<U> + ArrayLength<O> {
+            type Output = Matrix<O, R, C>;
+
+            default fn $func(self, rhs: &'a Matrix<U, R, C>) -> Self::Output {
+                Matrix(self.0.iter()
+                             .zip(rhs.0.iter())
+                             .map(|(a, b)| a.iter()
+                                            .zip(b.iter())
+                                            .map(|(i, j)| i.clone().$func(j.clone()))
+                                            .collect())
+                             .collect())
+            }
+        }
+    };
+    (assign, own, own => $Op:ident, $func:ident, $op:tt) => {
+        impl<R, C, T, U> $Op<Matrix<U, R, C>> for Matrix<T, R, C>
+            where T: InVector + $Op<U>,
+                  U: InVector,
+                  R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<U, C>>,
+                  C: ArrayLength<T> + ArrayLength<U> {
+
+            default fn $func(&mut self, rhs: Matrix<U, R, C>) {
+                self.iter_mut()
+                    .zip(rhs.0.into_iter().flat_map(|row| row.into_iter()))
+                    .for_each(|(i, j)| i.$func(j))
+            }
+        }
+    };
+    (assign, own, borrow => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, R, C, T, U: Clone> $Op<&'a Matrix<U, R, C>> for Matrix<T, R, C>
+            where T: InVector + $Op<U>,
+                  U: InVector,
+                  R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<U, C>>,
+                  C: ArrayLength<T> + ArrayLength<U> {
+
+            default fn $func(&mut self, rhs: &'a Matrix<U, R, C>) {
+                self.iter_mut()
+                    .zip(rhs.iter())
+                    .for_each(|(i, j)| i.$func(j.clone()))
+            }
+        }
+    };
+    (assign, borrow, own => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, R, C, T, U> $Op<Matrix<U, R, C>> for &'a mut Matrix<T, R, C>
+            where T: InVector + $Op<U>,
+                  U: InVector,
+                  R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<U, C>>,
+                  C: ArrayLength<T> + ArrayLength<U> {
+
+            default fn $func(&mut self, rhs: Matrix<U, R, C>) {
+                self.iter_mut()
+                    .zip(rhs.0.into_iter().flat_map(|row| row.into_iter()))
+                    .for_each(|(i, j)| i.$func(j))
+            }
+        }
+    };
+    (assign, borrow, borrow => $Op:ident, $func:ident, $op:tt) => {
+        impl<'a, R, C, T, U: Clone> $Op<&'a Matrix<U, R, C>> for &'a mut Matrix<T, R, C>
+            where T: InVector + $Op<U>,
+                  U: InVector,
+                  R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<U, C>>,
+                  C: ArrayLength<T> + ArrayLength<U> {
+
+            default fn $func(&mut self, rhs: &'a Matrix<U, R, C>) {
+                self.iter_mut()
+                    .zip(rhs.iter())
+                    .for_each(|(i, j)| i.$func(j.clone()))
+            }
+        }
+    };
+    (op => $Op:ident, $func:ident, $op:tt => $self_type:tt) => {
+        impl_op!($self_type => $Op, $func, $op);
+    };
+    (op all => $self_type:tt, $other_type:tt) => {
+        impl_op!(op => Add, add, + => $self_type, $other_type);
+        impl_op!(op => Sub, sub, - => $self_type, $other_type);
+        impl_op!(op => Div, div, / => $self_type, $other_type);
+        impl_op!(op => Rem, rem, % => $self_type, $other_type);
+
+        impl_op!(op => BitAnd, bitand, & => $self_type, $other_type);
+        impl_op!(op => BitOr, bitor, | => $self_type, $other_type);
+        impl_op!(op => BitXor, bitxor, ^ => $self_type, $other_type);
+        impl_op!(op => Shl, shl, << => $self_type, $other_type);
+        impl_op!(op => Shr, shr, >> => $self_type, $other_type);
+    };
+    (op assign all => $self_type:tt, $other_type:tt) => {
+        impl_op!(op assign => AddAssign, add_assign, += => $self_type, $other_type);
+        impl_op!(op assign => SubAssign, sub_assign, -= => $self_type, $other_type);
+        impl_op!(op assign => DivAssign, div_assign, /= => $self_type, $other_type);
+        impl_op!(op assign => RemAssign, rem_assign, %= => $self_type, $other_type);
+
+        impl_op!(op assign => BitAndAssign, bitand_assign, &= => $self_type, $other_type);
+        impl_op!(op assign => BitOrAssign, bitor_assign, |= => $self_type, $other_type);
+        impl_op!(op assign => BitXorAssign, bitxor_assign, ^= => $self_type, $other_type);
+        impl_op!(op assign => ShlAssign, shl_assign, <<= => $self_type, $other_type);
+        impl_op!(op assign => ShrAssign, shr_assign, >>= => $self_type, $other_type);
+    };
+    (op => $Op:ident, $func:ident, $op:tt => $self_type:tt, $other_type:tt) => {
+        impl_op!($self_type, $other_type => $Op, $func, $op);
+    };
+    (op assign => $Op:ident, $func:ident, $op:tt => $self_type:tt, $other_type:tt) => {
+        impl_op!(assign, $self_type, $other_type => $Op, $func, $op);
+    };
+}
+
+// `Mul` between two matrices is the matrix product, use `hadamard` for the element-wise product
+impl_op!(op all => own, own);
+impl_op!(op all => own, borrow);
+impl_op!(op all => borrow, own);
+impl_op!(op all => borrow, borrow);
+
+impl_op!(op assign all => own, own);
+impl_op!(op assign all => own, borrow);
+impl_op!(op assign all => borrow, own);
+impl_op!(op assign all => borrow, borrow);
+
+impl_op!(op => Mul, mul, * => own);
+impl_op!(op => Mul, mul, * => borrow);
+
+impl_op!(op => Div, div, / => own);
+impl_op!(op => Div, div, / => borrow);
+
+impl<T: InVector, R, C, O: InVector> Neg for Matrix<T, R, C>
+where T: Neg<Output = O>,
+      R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<O, C>>,
+      C: ArrayLength<T> + ArrayLength<O> {
+    type Output = Matrix<O, R, C>;
+
+    default fn neg(self) -> Self::Output {
+        self.map(|x| -x)
+    }
+}
+
+impl<'a, T: InVector + Clone, R, C, O: InVector> Neg for &'a Matrix<T, R, C>
+where T: Neg<Output = O>,
+      R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<O, C>>,
+      C: ArrayLength<T> + ArrayLength<O> {
+    type Output = Matrix<O, R, C>;
+
+    fn neg(self) -> Self::Output {
+        self.map_ref(|x| -x.clone())
+    }
+}
+
+impl<T: InVector, R, C, O: InVector> Not for Matrix<T, R, C>
+where T: Not<Output = O>,
+      R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<O, C>>,
+      C: ArrayLength<T> + ArrayLength<O> {
+    type Output = Matrix<O, R, C>;
+
+    default fn not(self) -> Self::Output {
+        self.map(|x| !x)
+    }
+}
+
+impl<'a, T: InVector + Clone, R, C, O: InVector> Not for &'a Matrix<T, R, C>
+where T: Not<Output = O>,
+      R: ArrayLength<GenericArray<T, C>> + ArrayLength<GenericArray<O, C>>,
+      C: ArrayLength<T> + ArrayLength<O> {
+    type Output = Matrix<O, R, C>;
+
+    fn not(self) -> Self::Output {
+        self.map_ref(|x| !x.clone())
+    }
+}
+
+fn mul_matrix_matrix<R, K, C, T, U, O>(lhs: &Matrix<T, R, K>, rhs: &Matrix<U, K, C>) -> Matrix<O, R, C>
+    where T: InVector + Clone + Mul<U, Output = O>,
+          U: InVector + Clone, O: InVector + Zero,
+          R: ArrayLength<GenericArray<T, K>> + ArrayLength<GenericArray<O, C>>,
+          K: ArrayLength<T> + ArrayLength<GenericArray<U, C>>,
+          C: ArrayLength<U> + ArrayLength<O> {
+    Matrix(lhs.0.iter().map(|row| {
+        (0..C::to_usize()).map(|c| {
+            row.iter()
+               .zip(rhs.0.iter())
+               .fold(O::zero(), |acc, (i, other)| acc + i.clone() * other[c].clone())
+        }).collect()
+    }).collect())
+}
+
+fn mul_matrix_vector<R, C, T, U, O>(lhs: &Matrix<T, R, C>, rhs: &Vector<U, C>) -> Vector<O, R>
+    where T: InVector + Clone + Mul<U, Output = O>,
+          U: InVector + Clone, O: InVector + Zero,
+          R: ArrayLength<GenericArray<T, C>> + ArrayLength<O>,
+          C: ArrayLength<T> + ArrayLength<U> {
+    Vector(lhs.0.iter().map(|row| {
+        row.iter().zip(rhs.iter()).fold(O::zero(), |acc, (i, j)| acc + i.clone() * j.clone())
+    }).collect())
+}
+
+fn mul_vector_matrix<R, C, T, U, O>(lhs: &Vector<T, R>, rhs: &Matrix<U, R, C>) -> Vector<O, C>
+    where T: InVector + Clone + Mul<U, Output = O>,
+          U: InVector + Clone, O: InVector + Zero,
+          R: ArrayLength<T> + ArrayLength<GenericArray<U, C>>,
+          C: ArrayLength<U> + ArrayLength<O> {
+    Vector((0..C::to_usize()).map(|c| {
+        lhs.iter()
+           .zip(rhs.0.iter())
+           .fold(O::zero(), |acc, (i, row)| acc + i.clone() * row[c].clone())
+    }).collect())
+}
+
+macro_rules! impl_mat_mul {
+    (@impl [$($gen:tt)*] $Lhs:ty, $Rhs:ty => $Out:ty, $fun:ident where $($bounds:tt)*) => {
+        impl<$($gen)*> Mul<$Rhs> for $Lhs where $($bounds)* {
+            type Output = $Out;
+
+            fn mul(self, rhs: $Rhs) -> Self::Output {
+                $fun(&self, &rhs)
+            }
+        }
+    };
+    ([$($gen:tt)*] $Lhs:ident<$($lp:ty),*>, $Rhs:ident<$($rp:ty),*> => $Out:ty, $fun:ident where $($bounds:tt)*) => {
+        impl_mat_mul!(@impl [$($gen)*] $Lhs<$($lp),*>, $Rhs<$($rp),*> => $Out, $fun where $($bounds)*);
+        impl_mat_mul!(@impl ['a, $($gen)*] $Lhs<$($lp),*>, &'a $Rhs<$($rp),*> => $Out, $fun where $($bounds)*);
+        impl_mat_mul!(@impl ['a, $($gen)*] &'a $Lhs<$($lp),*>, $Rhs<$($rp),*> => $Out, $fun where $($bounds)*);
+        impl_mat_mul!(@impl ['a, $($gen)*] &'a $Lhs<$($lp),*>, &'a $Rhs<$($rp),*> => $Out, $fun where $($bounds)*);
+    };
+}
+
+// the inner dimension `K` must match, so multiplying mismatched shapes is a type error
+impl_mat_mul!([R, K, C, T, U, O] Matrix<T, R, K>, Matrix<U, K, C> => Matrix<O, R, C>, mul_matrix_matrix
+    where T: InVector + Clone + Mul<U, Output = O>,
+          U: InVector + Clone, O: InVector + Zero,
+          R: ArrayLength<GenericArray<T, K>> + ArrayLength<GenericArray<O, C>>,
+          K: ArrayLength<T> + ArrayLength<GenericArray<U, C>>,
+          C: ArrayLength<U> + ArrayLength<O>);
+
+impl_mat_mul!([R, C, T, U, O] Matrix<T, R, C>, Vector<U, C> => Vector<O, R>, mul_matrix_vector
+    where T: InVector + Clone + Mul<U, Output = O>,
+          U: InVector + Clone, O: InVector + Zero,
+          R: ArrayLength<GenericArray<T, C>> + ArrayLength<O>,
+          C: ArrayLength<T> + ArrayLength<U>);
+
+impl_mat_mul!([R, C, T, U, O] Vector<T, R>, Matrix<U, R, C> => Vector<O, C>, mul_vector_matrix
+    where T: InVector + Clone + Mul<U, Output = O>,
+          U: InVector + Clone, O: InVector + Zero,
+          R: ArrayLength<T> + ArrayLength<GenericArray<U, C>>,
+          C: ArrayLength<U> + ArrayLength<O>);
@@ -6,6 +6,14 @@ pub mod vector_impl;
 pub mod vector_impl_spec;
 pub mod vector_ops;
 pub mod vector_ops_spec;
+pub mod matrix_impl;
+pub mod matrix_ops;
+pub mod cholesky;
+pub mod geometry;
+pub mod quaternion;
+pub mod transform;
+pub mod coords;
+pub mod norms;
 pub mod iter;
 
 #[cfg(test)]
@@ -33,9 +41,33 @@ impl<T: InVector + fmt::Debug, N: ArrayLength<T>> fmt::Debug for Vector<T, N> {
 
 impl<T, N> !InVector for Vector<T, N> {}
 
-#[derive(Debug)]
+/// a matrix with `R` rows and `C` columns, stored as an array of rows
+#[derive(Clone, PartialEq, Eq, Serialize)]
+#[serde(bound(serialize = "T: ::serde::Serialize"))]
+pub struct Matrix<T, R, C>(pub GenericArray<GenericArray<T, C>, R>)
+    where T: InVector,
+          R: ArrayLength<GenericArray<T, C>>,
+          C: ArrayLength<T>;
+
+impl<T: InVector + fmt::Debug, R, C> fmt::Debug for Matrix<T, R, C>
+    where R: ArrayLength<GenericArray<T, C>>,
+          C: ArrayLength<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl<T, R, C> !InVector for Matrix<T, R, C> {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct TryFromVectorError;
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct NotPositiveDefiniteError;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SingularMatrixError;
+
 pub struct RepeatN<T: Clone> {
     count: usize,
     value: T
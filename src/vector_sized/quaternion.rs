@@ -0,0 +1,217 @@
+use super::{Vector, Matrix, InVector};
+use super::geometry::Cross;
+
+#[cfg(feature = "no_std")]
+use core::ops::{Mul, Neg};
+#[cfg(not(feature = "no_std"))]
+use std::ops::{Mul, Neg};
+
+use num::traits::*;
+
+use super::typenum::U3;
+
+/// a quaternion `w + xi + yj + zk`, unit quaternions represent rotations in 3 dimensions
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Quaternion<T> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T
+}
+
+impl<T: InVector + Float> Quaternion<T> {
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    /// the quaternion representing no rotation
+    pub fn identity() -> Self {
+        Self::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+
+    /// creates a quaternion from a scalar and a vector part
+    pub fn from_parts(w: T, v: &Vector<T, U3>) -> Self {
+        Self::new(w, v[0], v[1], v[2])
+    }
+
+    /// the rotation by `angle` radians around `axis`, the axis does not need to be a unit vector
+    pub fn from_axis_angle(axis: &Vector<T, U3>, angle: T) -> Self {
+        let half = angle / (T::one() + T::one());
+        let axis = axis.norm();
+
+        Self::from_parts(half.cos(), &(axis * half.sin()))
+    }
+
+    /// the scalar part
+    pub fn scalar(&self) -> T {
+        self.w
+    }
+
+    /// the vector part
+    pub fn vector(&self) -> Vector<T, U3> {
+        Vector::<T, U3>::create(self.x, self.y, self.z)
+    }
+
+    /// takes the dot product of the two quaternions
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// the square of the magnitude
+    pub fn magsq(&self) -> T {
+        self.dot(self)
+    }
+
+    /// the magnitude
+    pub fn mag(&self) -> T {
+        self.magsq().sqrt()
+    }
+
+    /// returns a unit quaternion with the same direction as the parent quaternion
+    pub fn norm(&self) -> Self {
+        self.scale(self.mag().recip())
+    }
+
+    /// multiplies every component by `s`
+    pub fn scale(&self, s: T) -> Self {
+        Self::new(self.w * s, self.x * s, self.y * s, self.z * s)
+    }
+
+    /// negates the vector part, for unit quaternions this is the inverse rotation
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// the multiplicative inverse
+    pub fn inverse(&self) -> Self {
+        self.conjugate().scale(self.magsq().recip())
+    }
+
+    /// rotates a vector by this quaternion, `q * v * q^-1`
+    pub fn rotate(&self, v: &Vector<T, U3>) -> Vector<T, U3> {
+        (*self * Self::from_parts(T::zero(), v) * self.inverse()).vector()
+    }
+
+    /// spherical linear interpolation between two unit quaternions along the shortest arc
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let mut cos = self.dot(other);
+        let mut other = *other;
+
+        if cos < T::zero() {
+            other = -other;
+            cos = -cos;
+        }
+
+        // nearly parallel, so fall back to a normalized lerp to avoid dividing by `sin(0)`
+        if cos > T::one() - T::epsilon().sqrt() {
+            let lerp = Self::new(
+                self.w + (other.w - self.w) * t,
+                self.x + (other.x - self.x) * t,
+                self.y + (other.y - self.y) * t,
+                self.z + (other.z - self.z) * t
+            );
+
+            return lerp.norm()
+        }
+
+        let theta = cos.acos();
+        let sin = theta.sin();
+        let a = ((T::one() - t) * theta).sin() / sin;
+        let b = (t * theta).sin() / sin;
+
+        Self::new(
+            self.w * a + other.w * b,
+            self.x * a + other.x * b,
+            self.y * a + other.y * b,
+            self.z * a + other.z * b
+        )
+    }
+
+    /// the rotation matrix of a unit quaternion
+    pub fn to_rotation_matrix(&self) -> Matrix<T, U3, U3> {
+        let one = T::one();
+        let two = one + one;
+        let Quaternion { w, x, y, z } = *self;
+        let mut m = Matrix::new();
+
+        m[(0, 0)] = one - two * (y * y + z * z);
+        m[(0, 1)] = two * (x * y - z * w);
+        m[(0, 2)] = two * (x * z + y * w);
+        m[(1, 0)] = two * (x * y + z * w);
+        m[(1, 1)] = one - two * (x * x + z * z);
+        m[(1, 2)] = two * (y * z - x * w);
+        m[(2, 0)] = two * (x * z - y * w);
+        m[(2, 1)] = two * (y * z + x * w);
+        m[(2, 2)] = one - two * (x * x + y * y);
+
+        m
+    }
+
+    /// the unit quaternion of a rotation matrix
+    pub fn from_rotation_matrix(m: &Matrix<T, U3, U3>) -> Self {
+        let one = T::one();
+        let two = one + one;
+        let quarter = (two * two).recip();
+        let trace = m[(0, 0)] + m[(1, 1)] + m[(2, 2)];
+
+        // pick the largest component to divide by for numerical stability
+        let q = if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Self::new(s * quarter, (m[(2, 1)] - m[(1, 2)]) / s, (m[(0, 2)] - m[(2, 0)]) / s, (m[(1, 0)] - m[(0, 1)]) / s)
+        } else if m[(0, 0)] > m[(1, 1)] && m[(0, 0)] > m[(2, 2)] {
+            let s = (one + m[(0, 0)] - m[(1, 1)] - m[(2, 2)]).sqrt() * two;
+            Self::new((m[(2, 1)] - m[(1, 2)]) / s, s * quarter, (m[(0, 1)] + m[(1, 0)]) / s, (m[(0, 2)] + m[(2, 0)]) / s)
+        } else if m[(1, 1)] > m[(2, 2)] {
+            let s = (one + m[(1, 1)] - m[(0, 0)] - m[(2, 2)]).sqrt() * two;
+            Self::new((m[(0, 2)] - m[(2, 0)]) / s, (m[(0, 1)] + m[(1, 0)]) / s, s * quarter, (m[(1, 2)] + m[(2, 1)]) / s)
+        } else {
+            let s = (one + m[(2, 2)] - m[(0, 0)] - m[(1, 1)]).sqrt() * two;
+            Self::new((m[(1, 0)] - m[(0, 1)]) / s, (m[(0, 2)] + m[(2, 0)]) / s, (m[(1, 2)] + m[(2, 1)]) / s, s * quarter)
+        };
+
+        q.norm()
+    }
+}
+
+fn hamilton<T: InVector + Float>(a: &Quaternion<T>, b: &Quaternion<T>) -> Quaternion<T> {
+    let v = a.vector().cross(&b.vector()) + b.vector() * a.w + a.vector() * b.w;
+
+    Quaternion::from_parts(a.w * b.w - a.vector().dot(&b.vector()), &v)
+}
+
+macro_rules! impl_hamilton {
+    () => {
+        impl_hamilton!(@impl [] Quaternion<T>, Quaternion<T>);
+        impl_hamilton!(@impl ['a,] Quaternion<T>, &'a Quaternion<T>);
+        impl_hamilton!(@impl ['a,] &'a Quaternion<T>, Quaternion<T>);
+        impl_hamilton!(@impl ['a,] &'a Quaternion<T>, &'a Quaternion<T>);
+    };
+    (@impl [$($lt:tt)*] $Lhs:ty, $Rhs:ty) => {
+        impl<$($lt)* T: InVector + Float> Mul<$Rhs> for $Lhs {
+            type Output = Quaternion<T>;
+
+            /// the Hamilton product
+            fn mul(self, rhs: $Rhs) -> Self::Output {
+                hamilton(&self, &rhs)
+            }
+        }
+    };
+}
+
+impl_hamilton!();
+
+impl<T: InVector + Float> Neg for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn neg(self) -> Self::Output {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl<'a, T: InVector + Float> Neg for &'a Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
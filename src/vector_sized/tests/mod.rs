@@ -0,0 +1,219 @@
+use super::*;
+use super::typenum::{U2, U3};
+
+#[cfg(feature = "no_std")]
+use core::convert::TryFrom;
+#[cfg(not(feature = "no_std"))]
+use std::convert::TryFrom;
+
+type MatrixI<R, C> = Matrix<i32, R, C>;
+
+#[test]
+fn matrix_init() {
+    let m = MatrixI::<U2, U3>::new();
+
+    assert_eq!(m.dim(), (2, 3));
+    assert!(m.iter().all(|&x| x == 0))
+}
+
+#[test]
+fn matrix_identity() {
+    let m = MatrixI::<U2, U2>::identity();
+
+    assert_eq!(m, MatrixI::try_from(&[1, 0, 0, 1][..]).unwrap())
+}
+
+#[test]
+fn matrix_index() {
+    let m = MatrixI::<U2, U3>::try_from(&[1, 2, 3, 4, 5, 6][..]).unwrap();
+
+    assert_eq!(m[(0, 2)], 3);
+    assert_eq!(m.0[1][0], 4);
+    assert_eq!(m.col(1), Vector::<i32, U2>::create(2, 5))
+}
+
+#[test]
+fn matrix_transpose() {
+    let m = MatrixI::<U2, U3>::try_from(&[1, 2, 3, 4, 5, 6][..]).unwrap();
+    let t = MatrixI::<U3, U2>::try_from(&[1, 4, 2, 5, 3, 6][..]).unwrap();
+
+    assert_eq!(m.transpose(), t)
+}
+
+#[test]
+fn matrix_elementwise() {
+    let a = MatrixI::<U2, U2>::try_from(&[1, 2, 3, 4][..]).unwrap();
+    let b = MatrixI::<U2, U2>::try_from(&[5, 6, 7, 8][..]).unwrap();
+
+    assert_eq!(&a + &b, MatrixI::try_from(&[6, 8, 10, 12][..]).unwrap());
+    assert_eq!(a * 2, MatrixI::try_from(&[2, 4, 6, 8][..]).unwrap())
+}
+
+#[test]
+fn matrix_product() {
+    let a = MatrixI::<U2, U3>::try_from(&[1, 2, 3, 4, 5, 6][..]).unwrap();
+    let b = MatrixI::<U3, U2>::try_from(&[7, 8, 9, 10, 11, 12][..]).unwrap();
+    let v = Vector::<i32, U3>::create(1, 0, 2);
+    let w = Vector::<i32, U2>::create(1, 1);
+
+    assert_eq!(&a * &b, MatrixI::<U2, U2>::try_from(&[58, 64, 139, 154][..]).unwrap());
+    assert_eq!(&a * &v, Vector::<i32, U2>::create(7, 16));
+    assert_eq!(w * a, Vector::<i32, U3>::create(5, 7, 9))
+}
+
+#[test]
+fn matrix_cholesky() {
+    let a = Matrix::<f64, U2, U2>::try_from(&[4.0, 2.0, 2.0, 5.0][..]).unwrap();
+    let chol = a.cholesky().unwrap();
+    let x = chol.solve(&Vector::<f64, U2>::create(6.0, 7.0));
+
+    assert_eq!(chol.l(), Matrix::try_from(&[2.0, 0.0, 1.0, 2.0][..]).unwrap());
+    assert!((x[0] - 1.0).abs() < 1e-9 && (x[1] - 1.0).abs() < 1e-9);
+    assert!((chol.log_det() - 16.0f64.ln()).abs() < 1e-9);
+    assert!(Matrix::<f64, U2, U2>::try_from(&[1.0, 2.0, 2.0, 1.0][..]).unwrap().cholesky().is_err())
+}
+
+#[test]
+fn cross() {
+    use super::geometry::{Cross, PerpDot};
+
+    let x = Vector::<i32, U3>::create(1, 0, 0);
+    let y = Vector::<i32, U3>::create(0, 1, 0);
+    let z = Vector::<i32, U3>::create(0, 0, 1);
+    let a = Vector::<f64, U3>::create(1.0, 2.0, 3.0);
+    let b = Vector::<f64, U3>::create(4.0, 5.0, 6.0);
+
+    assert_eq!(x.cross(&y), z);
+    assert_eq!(a.cross(&b), Vector::<f64, U3>::create(-3.0, 6.0, -3.0));
+    assert_eq!(Vector::<f32, U2>::create(1.0, 2.0).perp_dot(&Vector::<f32, U2>::create(3.0, 4.0)), -2.0);
+    assert_eq!(Vector::<i32, U2>::create(1, 2).perp_dot(&Vector::<i32, U2>::create(3, 4)), -2)
+}
+
+#[test]
+fn triple_products() {
+    let a = Vector::<i32, U3>::create(1, 2, 3);
+    let b = Vector::<i32, U3>::create(4, 5, 6);
+    let c = Vector::<i32, U3>::create(7, 8, 10);
+
+    assert_eq!(a.scalar_triple(&b, &c), -3);
+    // a x (b x c) = b (a . c) - c (a . b)
+    assert_eq!(a.vector_triple(&b, &c), &b * a.dot(&c) - &c * a.dot(&b))
+}
+
+#[test]
+fn quaternion() {
+    use super::quaternion::Quaternion;
+    use std::f64::consts::FRAC_PI_2;
+
+    let close = |a: &Vector<f64, U3>, b: &Vector<f64, U3>| (a - b).mag() < 1e-9;
+    let z = Vector::<f64, U3>::create(0.0, 0.0, 2.0);
+    let x = Vector::<f64, U3>::create(1.0, 0.0, 0.0);
+    let y = Vector::<f64, U3>::create(0.0, 1.0, 0.0);
+    let q = Quaternion::from_axis_angle(&z, FRAC_PI_2);
+
+    assert!(close(&q.rotate(&x), &y));
+    assert!(close(&(q * q).rotate(&x), &-&x));
+    assert!(((q * q.inverse()).w - 1.0).abs() < 1e-9);
+    assert!(close(&(q.to_rotation_matrix() * &x), &y));
+
+    let back = Quaternion::from_rotation_matrix(&q.to_rotation_matrix());
+    assert!((back.dot(&q).abs() - 1.0).abs() < 1e-9);
+
+    let half = Quaternion::identity().slerp(&q, 0.5);
+    let expected = Quaternion::from_axis_angle(&z, FRAC_PI_2 / 2.0);
+    assert!((half.dot(&expected) - 1.0).abs() < 1e-9)
+}
+
+#[test]
+fn transform() {
+    use super::transform::{Transform2, Transform3};
+    use std::f64::consts::FRAC_PI_2;
+
+    let close3 = |a: &Vector<f64, U3>, b: &Vector<f64, U3>| (a - b).mag() < 1e-9;
+    let close2 = |a: &Vector<f64, U2>, b: &Vector<f64, U2>| (a - b).mag() < 1e-9;
+    let p = Vector::<f64, U3>::create(1.0, 0.0, 0.0);
+    let z = Vector::<f64, U3>::create(0.0, 0.0, 1.0);
+
+    let t = Transform3::translation(&Vector::<f64, U3>::create(0.0, 0.0, 5.0))
+        * Transform3::rotation_axis_angle(&z, FRAC_PI_2);
+
+    assert!(close3(&t.transform_point(&p), &Vector::<f64, U3>::create(0.0, 1.0, 5.0)));
+    assert!(close3(&t.transform_vector(&p), &Vector::<f64, U3>::create(0.0, 1.0, 0.0)));
+    assert!(close3(&t.inverse().unwrap().transform_point(&t.transform_point(&p)), &p));
+    assert_eq!(Transform3::uniform_scale(0.0).inverse(), Err(SingularMatrixError));
+
+    let r = Transform2::rotation(FRAC_PI_2).then(&Transform2::translation(&Vector::<f64, U2>::create(1.0, 1.0)));
+    assert!(close2(&r.transform_point(&Vector::<f64, U2>::create(1.0, 0.0)), &Vector::<f64, U2>::create(1.0, 2.0)));
+}
+
+#[test]
+fn projection() {
+    use super::transform::Transform3;
+    use std::f64::consts::FRAC_PI_2;
+
+    let close = |a: &Vector<f64, U3>, b: &Vector<f64, U3>| (a - b).mag() < 1e-9;
+    let eye = Vector::<f64, U3>::create(0.0, 0.0, 5.0);
+    let view = Transform3::look_at(&eye, &Vector::new(), &Vector::<f64, U3>::create(0.0, 1.0, 0.0));
+
+    assert!(close(&view.transform_point(&Vector::new()), &Vector::<f64, U3>::create(0.0, 0.0, -5.0)));
+
+    let proj = Transform3::perspective(FRAC_PI_2, 1.0, 1.0, 10.0);
+    assert!(close(&proj.transform_point(&Vector::<f64, U3>::create(1.0, 1.0, -1.0)), &Vector::<f64, U3>::create(1.0, 1.0, -1.0)));
+    assert!(close(&proj.transform_point(&Vector::<f64, U3>::create(0.0, 0.0, -10.0)), &Vector::<f64, U3>::create(0.0, 0.0, 1.0)));
+
+    let ortho = Transform3::orthographic(-2.0, 2.0, -1.0, 1.0, 0.0, 4.0);
+    assert!(close(&ortho.transform_point(&Vector::<f64, U3>::create(2.0, -1.0, -4.0)), &Vector::<f64, U3>::create(1.0, -1.0, 1.0)));
+}
+
+#[test]
+fn matrix_serde() {
+    use serde::{Serialize, Deserialize};
+
+    fn is_serde<S: Serialize + for<'de> Deserialize<'de>>() {}
+
+    is_serde::<Matrix<f64, U2, U3>>();
+}
+
+#[test]
+fn coords() {
+    use coords::{Polar, Spherical, Cylindrical};
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    let v = Vector::<f64, U2>::from_polar(&Polar::new(2.0, PI));
+    assert!((v - Vector::<f64, U2>::create(-2.0, 0.0)).mag() < 1e-12);
+
+    let z = Vector::<f64, U3>::create(0.0, 0.0, 1.0).to_spherical();
+    assert_eq!(z, Spherical::new(1.0, 0.0, 0.0));
+
+    let s = Spherical::new(1.0, FRAC_PI_2, FRAC_PI_2);
+    assert!((Vector::<f64, U3>::from_spherical(&s) - Vector::<f64, U3>::create(0.0, 1.0, 0.0)).mag() < 1e-12);
+
+    let c = Cylindrical::from(s);
+    assert!((c.r - 1.0).abs() < 1e-12 && c.z.abs() < 1e-12);
+
+    let v = Vector::<f64, U3>::create(1.0, -2.0, 3.0);
+    assert!((Vector::<f64, U3>::from_cylindrical(&v.to_cylindrical()) - &v).mag() < 1e-12)
+}
+
+#[test]
+fn norms() {
+    let v1 = Vector::<f64, U2>::create(3.0, -4.0);
+    let v2 = Vector::<f64, U2>::create(1.0, 1.0);
+
+    assert_eq!(v1.norm_l1(), 7.0);
+    assert_eq!(v1.norm_inf(), 4.0);
+    assert!((v1.norm_p(3.0) - 91.0f64.cbrt()).abs() < 1e-12);
+    assert_eq!(v1.norm_weighted(&Vector::<f64, U2>::create(0.0, 1.0)), 4.0);
+    assert_eq!(v1.distance_l1(&v2), 7.0);
+    assert_eq!(v1.distance_inf(&v2), 5.0)
+}
+
+#[test]
+fn compensated_sums() {
+    let v = Vector::<f64, U3>::create(1e16, 1.0, -1e16);
+    let ones = Vector::<f64, U3>::create(1.0, 1.0, 1.0);
+
+    assert_eq!(v.sum_kahan(), 1.0);
+    assert_eq!(v.dot_compensated(&ones), 1.0);
+    assert!((Vector::<f64, U2>::create(3e200, 4e200).mag_scaled() / 5e200 - 1.0).abs() < 1e-12)
+}
@@ -0,0 +1,223 @@
+use super::{Vector, Matrix, InVector, SingularMatrixError};
+use super::geometry::Cross;
+use super::quaternion::Quaternion;
+
+#[cfg(feature = "no_std")]
+use core::ops::Mul;
+#[cfg(not(feature = "no_std"))]
+use std::ops::Mul;
+
+use num::traits::*;
+
+use super::typenum::{Unsigned, U2, U3, U4};
+
+macro_rules! impl_transform {
+    ($Transform:ident, $Dim:ident, $Hom:ident) => {
+        /// an affine or projective transform stored as a homogeneous matrix,
+        /// acting on column vectors so `a * b` applies `b` first
+        #[derive(Clone, PartialEq, Debug)]
+        pub struct $Transform<T: InVector> {
+            matrix: Matrix<T, $Hom, $Hom>
+        }
+
+        impl<T: InVector + Float> $Transform<T> {
+            /// the transform that leaves everything in place
+            pub fn identity() -> Self {
+                $Transform { matrix: Matrix::identity() }
+            }
+
+            /// wraps a homogeneous matrix
+            pub fn from_matrix(matrix: Matrix<T, $Hom, $Hom>) -> Self {
+                $Transform { matrix }
+            }
+
+            /// the homogeneous matrix of the transform
+            pub fn matrix(&self) -> &Matrix<T, $Hom, $Hom> {
+                &self.matrix
+            }
+
+            /// unwraps the homogeneous matrix of the transform
+            pub fn into_matrix(self) -> Matrix<T, $Hom, $Hom> {
+                self.matrix
+            }
+
+            /// moves points by `offset`, vectors are unaffected
+            pub fn translation(offset: &Vector<T, $Dim>) -> Self {
+                let mut matrix = Matrix::identity();
+
+                for i in 0..$Dim::to_usize() {
+                    matrix[(i, $Dim::to_usize())] = offset[i];
+                }
+
+                $Transform { matrix }
+            }
+
+            /// scales each axis by the corresponding component of `factors`
+            pub fn scale(factors: &Vector<T, $Dim>) -> Self {
+                let mut matrix = Matrix::identity();
+
+                for i in 0..$Dim::to_usize() {
+                    matrix[(i, i)] = factors[i];
+                }
+
+                $Transform { matrix }
+            }
+
+            /// scales every axis by `factor`
+            pub fn uniform_scale(factor: T) -> Self {
+                let mut factors = Vector::new();
+
+                for i in 0..$Dim::to_usize() {
+                    factors[i] = factor;
+                }
+
+                Self::scale(&factors)
+            }
+
+            /// applies `self` and then `other`, equivalent to `other * self`
+            pub fn then(&self, other: &Self) -> Self {
+                other * self
+            }
+
+            /// the inverse transform, or an error if the transform is not invertible
+            pub fn inverse(&self) -> Result<Self, SingularMatrixError> {
+                self.matrix.inverse().map(|matrix| $Transform { matrix })
+            }
+
+            /// transforms a point, applying translation and the perspective divide
+            pub fn transform_point(&self, point: &Vector<T, $Dim>) -> Vector<T, $Dim> {
+                let dim = $Dim::to_usize();
+                let row = |i: usize| {
+                    (0..dim).fold(self.matrix[(i, dim)], |acc, j| acc + self.matrix[(i, j)] * point[j])
+                };
+                let w = row(dim);
+                let mut out = Vector::new();
+
+                for i in 0..dim {
+                    out[i] = row(i) / w;
+                }
+
+                out
+            }
+
+            /// transforms a direction, ignoring translation
+            pub fn transform_vector(&self, vector: &Vector<T, $Dim>) -> Vector<T, $Dim> {
+                let dim = $Dim::to_usize();
+                let mut out = Vector::new();
+
+                for i in 0..dim {
+                    out[i] = (0..dim).fold(T::zero(), |acc, j| acc + self.matrix[(i, j)] * vector[j]);
+                }
+
+                out
+            }
+        }
+
+        impl_transform!(@mul $Transform, [] $Transform<T>, $Transform<T>);
+        impl_transform!(@mul $Transform, ['a,] $Transform<T>, &'a $Transform<T>);
+        impl_transform!(@mul $Transform, ['a,] &'a $Transform<T>, $Transform<T>);
+        impl_transform!(@mul $Transform, ['a,] &'a $Transform<T>, &'a $Transform<T>);
+    };
+    (@mul $Transform:ident, [$($lt:tt)*] $Lhs:ty, $Rhs:ty) => {
+        impl<$($lt)* T: InVector + Float> Mul<$Rhs> for $Lhs {
+            type Output = $Transform<T>;
+
+            // composes the transforms, `rhs` is applied first
+            fn mul(self, rhs: $Rhs) -> Self::Output {
+                $Transform { matrix: &self.matrix * &rhs.matrix }
+            }
+        }
+    };
+}
+
+impl_transform!(Transform2, U2, U3);
+impl_transform!(Transform3, U3, U4);
+
+impl<T: InVector + Float> Transform2<T> {
+    /// rotates counter clockwise by `angle` radians around the origin
+    pub fn rotation(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let mut matrix = Matrix::identity();
+
+        matrix[(0, 0)] = cos;
+        matrix[(0, 1)] = -sin;
+        matrix[(1, 0)] = sin;
+        matrix[(1, 1)] = cos;
+
+        Transform2 { matrix }
+    }
+}
+
+impl<T: InVector + Float> Transform3<T> {
+    /// rotates by a unit quaternion around the origin
+    pub fn rotation(rotation: &Quaternion<T>) -> Self {
+        let rot = rotation.to_rotation_matrix();
+        let mut matrix = Matrix::identity();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                matrix[(i, j)] = rot[(i, j)];
+            }
+        }
+
+        Transform3 { matrix }
+    }
+
+    /// rotates by `angle` radians around `axis` through the origin
+    pub fn rotation_axis_angle(axis: &Vector<T, U3>, angle: T) -> Self {
+        Self::rotation(&Quaternion::from_axis_angle(axis, angle))
+    }
+
+    /// a right handed view transform for a camera at `eye` looking at `target`,
+    /// the camera looks down its negative z axis
+    pub fn look_at(eye: &Vector<T, U3>, target: &Vector<T, U3>, up: &Vector<T, U3>) -> Self {
+        let forward = (target - eye).norm();
+        let side = forward.cross(up).norm();
+        let up = side.cross(&forward);
+        let mut matrix = Matrix::identity();
+
+        for j in 0..3 {
+            matrix[(0, j)] = side[j];
+            matrix[(1, j)] = up[j];
+            matrix[(2, j)] = -forward[j];
+        }
+
+        matrix[(0, 3)] = -side.dot(eye);
+        matrix[(1, 3)] = -up.dot(eye);
+        matrix[(2, 3)] = forward.dot(eye);
+
+        Transform3 { matrix }
+    }
+
+    /// a right handed perspective projection mapping the view frustum to `[-1, 1]` on every axis,
+    /// `fov_y` is the vertical field of view in radians
+    pub fn perspective(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        let one = T::one();
+        let two = one + one;
+        let f = (fov_y / two).tan().recip();
+        let mut matrix = Matrix::new();
+
+        matrix[(0, 0)] = f / aspect;
+        matrix[(1, 1)] = f;
+        matrix[(2, 2)] = (far + near) / (near - far);
+        matrix[(2, 3)] = two * far * near / (near - far);
+        matrix[(3, 2)] = -one;
+
+        Transform3 { matrix }
+    }
+
+    /// a right handed orthographic projection mapping the given box to `[-1, 1]` on every axis
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::one() + T::one();
+        let mut matrix = Matrix::identity();
+
+        matrix[(0, 0)] = two / (right - left);
+        matrix[(1, 1)] = two / (top - bottom);
+        matrix[(2, 2)] = -two / (far - near);
+        matrix[(0, 3)] = -(right + left) / (right - left);
+        matrix[(1, 3)] = -(top + bottom) / (top - bottom);
+        matrix[(2, 3)] = -(far + near) / (far - near);
+
+        Transform3 { matrix }
+    }
+}
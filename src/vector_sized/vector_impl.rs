@@ -1,4 +1,5 @@
 use super::{Vector, InVector, TryFromVectorError};
+use summation;
 use super::typenum::marker_traits::NonZero;
 
 #[cfg(feature = "no_std")]
@@ -177,6 +178,29 @@ impl<T: InVector, N: ArrayLength<T>> Vector<T, N>
     }
 }
 
+impl<T: InVector, N: ArrayLength<T>> Vector<T, N>
+    where T: Float {
+    /// the magnitude, scaled so that it does not overflow or underflow for extreme components
+    pub fn mag_scaled(&self) -> T {
+        summation::nrm2(self)
+    }
+
+    /// sums up the elements of the vector using compensated summation
+    pub fn sum_kahan(&self) -> T {
+        summation::sum_kahan(self)
+    }
+
+    /// sums up the elements of the vector using pairwise summation
+    pub fn sum_pairwise(&self) -> T {
+        summation::sum_pairwise(self)
+    }
+
+    /// takes the dot product of the two vectors in compensated arithmetic
+    pub fn dot_compensated(&self, other: &Self) -> T {
+        summation::dot_compensated(self, other)
+    }
+}
+
 impl<T: InVector, N: ArrayLength<T>> Vector<T, N> 
 where T: Add<Output = T> {
     /// adds the shift value to all the elements in a vector
@@ -124,6 +124,7 @@ impl_spec!(block ty sign => isize, ComplexIsize);
 #[test]
 fn t1() {
     use std::convert::TryFrom;
+    use vector_sized::typenum::U2;
 
     let v: Vector<f32, U2> = Vector::try_from(vec![0.0, 1.0]).unwrap();
     let w: Vector<f32, U2> = Vector::try_from(vec![2.0, 3.0]).unwrap();